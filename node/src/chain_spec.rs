@@ -1,7 +1,8 @@
 use node_template_runtime::{
 	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	SystemConfig, TemplateModuleConfig, WASM_BINARY,
 };
+use pallet_club::GenesisClub;
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -36,6 +37,35 @@ pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
 	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
+/// The end of the demo memberships: 2030-01-01T00:00:00Z.
+const DEMO_MEMBERSHIP_END: u64 = 1_893_456_000_000;
+
+/// Clubs to try the club pallet with.
+fn demo_clubs() -> Vec<GenesisClub<AccountId>> {
+	let account = get_account_id_from_seed::<sr25519::Public>;
+	vec![
+		GenesisClub {
+			id: 0,
+			owner: account("Alice"),
+			price: 10,
+			members: vec![
+				(account("Bob"), DEMO_MEMBERSHIP_END),
+				(account("Charlie"), DEMO_MEMBERSHIP_END),
+			],
+			lifetime: vec![],
+			suspended: vec![],
+		},
+		GenesisClub {
+			id: 1,
+			owner: account("Bob"),
+			price: 25,
+			members: vec![(account("Alice"), DEMO_MEMBERSHIP_END)],
+			lifetime: vec![],
+			suspended: vec![],
+		},
+	]
+}
+
 pub fn development_config() -> Result<ChainSpec, String> {
 	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
 
@@ -59,6 +89,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				demo_clubs(),
 				true,
 			)
 		},
@@ -107,6 +138,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				demo_clubs(),
 				true,
 			)
 		},
@@ -130,6 +162,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	clubs: Vec<GenesisClub<AccountId>>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -152,5 +185,6 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		template_module: TemplateModuleConfig { clubs },
 	}
 }
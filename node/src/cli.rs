@@ -7,6 +7,38 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	#[clap(flatten)]
+	pub club_webhook: ClubWebhookParams,
+}
+
+/// Parameters of the club expiry notifications.
+#[derive(Debug, Clone, clap::Args)]
+pub struct ClubWebhookParams {
+	/// Local HTTP endpoint to POST a JSON notification to for each club membership about to
+	/// expire. Notifications are only sent when this is set and offchain workers are enabled.
+	#[arg(long, value_name = "URL", value_parser = parse_webhook_url)]
+	pub club_webhook_url: Option<String>,
+
+	/// How long before expiry, in milliseconds, members are notified.
+	#[arg(long, value_name = "MS", default_value_t = 7 * 24 * 60 * 60 * 1000)]
+	pub club_webhook_window: u64,
+}
+
+fn parse_webhook_url(url: &str) -> Result<String, String> {
+	pallet_club::validate_webhook_url(url.as_bytes())
+		.map(Into::into)
+		.map_err(|e| format!("invalid webhook URL: {:?}", e))
+}
+
+impl ClubWebhookParams {
+	/// The webhook configuration handed to the offchain worker, if a URL is set.
+	pub fn config(&self) -> Option<pallet_club::WebhookConfig> {
+		self.club_webhook_url.as_ref().map(|url| pallet_club::WebhookConfig {
+			url: url.as_bytes().to_vec(),
+			window: self.club_webhook_window,
+		})
+	}
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -50,4 +82,8 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Tools for club data.
+	#[command(subcommand)]
+	Club(crate::club::ClubCmd),
 }
@@ -0,0 +1,690 @@
+//! `club` subcommands, working on club data without a running node.
+
+use std::{
+	fs,
+	io::{self, Write},
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use frame_support::traits::{GenesisBuild, Get};
+use node_template_runtime::{opaque::Block, AccountId, Balance, Hash, Moment, Runtime};
+use pallet_club::{validate_genesis_clubs, GenesisClub, MemberInfo, PalletStorage};
+use pallet_club_rpc::{ClubId, ClubRuntimeApi, MAX_MEMBERS_PAGE};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, SharedParams};
+use sc_service::ChainSpec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{bytes, crypto::Ss58Codec, hexdisplay::HexDisplay, storage::Storage};
+
+/// Tools for club data.
+#[derive(Debug, clap::Subcommand)]
+pub enum ClubCmd {
+	/// Export the clubs and their members at a block of the local database. The runtime of the
+	/// block must implement version 5 of the club runtime API.
+	Export(ExportCmd),
+	/// Add the clubs of an export file to the genesis of a chain spec.
+	ImportIntoSpec(ImportIntoSpecCmd),
+}
+
+/// The file formats of club data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+	/// A JSON array of clubs, each with its members.
+	Json,
+	/// One row per membership, with the club repeated on each row. Clubs without members get a
+	/// row with empty member columns.
+	Csv,
+}
+
+/// The header of the CSV format. Dependents get a row with their primary member and without
+/// expiry.
+const CSV_HEADER: &str =
+	"club_id,owner,price,member,expires_at,expires_at_ms,lifetime,suspended,stake,primary";
+
+/// A club in an export file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClubRecord {
+	/// The id of the club.
+	pub id: ClubId,
+	/// The SS58 address of the owner.
+	pub owner: String,
+	/// The annual membership price.
+	pub price: u32,
+	/// The members of the club.
+	pub members: Vec<MemberRecord>,
+	/// The dependents attached to the group memberships of members.
+	#[serde(default)]
+	pub dependents: Vec<DependentRecord>,
+}
+
+/// A membership in an export file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberRecord {
+	/// The SS58 address of the member.
+	pub account: String,
+	/// The end of the membership as an RFC 3339 date, for humans.
+	pub expires_at: String,
+	/// The end of the membership in milliseconds since the unix epoch. For stake memberships
+	/// this is when the member joined.
+	pub expires_at_ms: u64,
+	/// Whether the membership is for life, whatever its end.
+	#[serde(default)]
+	pub lifetime: bool,
+	/// Whether the membership is suspended.
+	#[serde(default)]
+	pub suspended: bool,
+	/// The amount staked, for memberships held by a stake.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub stake: Option<u32>,
+}
+
+/// A dependent in an export file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependentRecord {
+	/// The SS58 address of the dependent.
+	pub account: String,
+	/// The SS58 address of the primary member.
+	pub primary: String,
+}
+
+impl ClubRecord {
+	/// The club as configured in the genesis of the club pallet.
+	///
+	/// Stakes are funds reserved on the exporting chain and dependents need the group tier of
+	/// the club, neither of which the genesis config can restore. Stake members and dependents
+	/// are left out, with a warning added to `warnings`.
+	fn into_genesis(self, warnings: &mut Vec<String>) -> Result<GenesisClub<AccountId>, String> {
+		let account = |address: &str| {
+			AccountId::from_ss58check(address)
+				.map_err(|e| format!("club {}: invalid address {}: {:?}", self.id, address, e))
+		};
+		let mut club = GenesisClub {
+			id: self.id,
+			owner: account(&self.owner)?,
+			price: self.price,
+			members: Vec::new(),
+			lifetime: Vec::new(),
+			suspended: Vec::new(),
+		};
+		for member in &self.members {
+			if member.stake.is_some() {
+				warnings.push(format!(
+					"club {}: skipping {}, stake memberships are not imported",
+					self.id, member.account
+				));
+				continue
+			}
+			let who = account(&member.account)?;
+			if member.lifetime {
+				club.lifetime.push(who.clone());
+			}
+			if member.suspended {
+				club.suspended.push(who.clone());
+			}
+			club.members.push((who, member.expires_at_ms));
+		}
+		for dependent in &self.dependents {
+			warnings.push(format!(
+				"club {}: skipping {}, dependents of {} are not imported",
+				self.id, dependent.account, dependent.primary
+			));
+		}
+		Ok(club)
+	}
+}
+
+impl MemberRecord {
+	fn new(member: &MemberInfo<AccountId, Moment>, stake: Option<u32>) -> Self {
+		Self {
+			account: member.account.to_ss58check(),
+			expires_at: format_timestamp(member.expires_at),
+			expires_at_ms: member.expires_at,
+			lifetime: member.lifetime,
+			suspended: member.suspended,
+			stake,
+		}
+	}
+}
+
+/// The `club export` command.
+///
+/// The clubs are read through the club runtime API, of which version 5 added the stakes and
+/// dependents. Blocks of older runtimes, including runtimes without the API, are refused rather
+/// than exported without them; export at a block after the runtime upgrade instead.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCmd {
+	/// Block hash or number to export the clubs at. Defaults to the best block. Its runtime must
+	/// implement version 5 of the club runtime API.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// The format to write.
+	#[arg(long, value_enum, default_value_t = Format::Json)]
+	pub format: Format,
+
+	/// The file to write to. Defaults to stdout.
+	#[arg(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportCmd {
+	/// Run the export against the state of `client`.
+	pub fn run<C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+		C::Api: ClubRuntimeApi<Block, AccountId, Moment>,
+	{
+		let hash = match &self.at {
+			Some(at) => client.expect_block_hash_from_id(&at.parse()?)?,
+			None => client.info().best_hash,
+		};
+		let clubs = read_clubs(&*client, hash)?;
+
+		let mut out: Box<dyn Write> = match &self.output {
+			Some(path) => Box::new(io::BufWriter::new(fs::File::create(path)?)),
+			None => Box::new(io::stdout().lock()),
+		};
+		match self.format {
+			Format::Json => {
+				serde_json::to_writer_pretty(&mut out, &clubs).map_err(|e| e.to_string())?;
+				writeln!(out)?;
+			},
+			Format::Csv => write_csv(&mut out, &clubs)?,
+		}
+		out.flush()?;
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// All clubs and their members at block `hash`.
+fn read_clubs<C>(client: &C, hash: Hash) -> sc_cli::Result<Vec<ClubRecord>>
+where
+	C: ProvideRuntimeApi<Block>,
+	C::Api: ClubRuntimeApi<Block, AccountId, Moment>,
+{
+	let api_error = |e: sp_api::ApiError| sc_cli::Error::Application(Box::new(e));
+	let api = client.runtime_api();
+	let version = api
+		.api_version::<dyn ClubRuntimeApi<Block, AccountId, Moment>>(hash)
+		.map_err(api_error)?;
+	ensure_export_supported(version)?;
+
+	let mut clubs = Vec::new();
+	for id in api.club_ids(hash).map_err(api_error)? {
+		let Some(club) = api.club(hash, id).map_err(api_error)? else { continue };
+		let stakes = api.stakes(hash, id).map_err(api_error)?;
+		let stake_of = |who: &AccountId| {
+			stakes.iter().find(|(staker, _)| staker == who).map(|(_, amount)| *amount)
+		};
+		let mut members = Vec::new();
+		let mut cursor = None;
+		loop {
+			let page = api.members(hash, id, cursor, MAX_MEMBERS_PAGE).map_err(api_error)?;
+			let last_page = (page.len() as u32) < MAX_MEMBERS_PAGE;
+			cursor = page.last().map(|member| member.account.clone());
+			members.extend(
+				page.iter().map(|member| MemberRecord::new(member, stake_of(&member.account))),
+			);
+			if last_page {
+				break
+			}
+		}
+		let dependents = api
+			.dependents(hash, id)
+			.map_err(api_error)?
+			.into_iter()
+			.map(|(dependent, primary)| DependentRecord {
+				account: dependent.to_ss58check(),
+				primary: primary.to_ss58check(),
+			})
+			.collect();
+		clubs.push(ClubRecord {
+			id,
+			owner: club.owner.to_ss58check(),
+			price: club.annual_expenses,
+			members,
+			dependents,
+		});
+	}
+	Ok(clubs)
+}
+
+/// The version of the club runtime API needed to export clubs: the first with `stakes` and
+/// `dependents`.
+const EXPORT_API_VERSION: u32 = 5;
+
+/// Check that a runtime implementing `version` of the club runtime API, or `None` if it does not
+/// implement it, can be exported.
+fn ensure_export_supported(version: Option<u32>) -> sc_cli::Result<()> {
+	match version {
+		Some(version) if version >= EXPORT_API_VERSION => Ok(()),
+		Some(version) => Err(sc_cli::Error::Input(format!(
+			"the runtime at this block implements version {} of the club runtime API, \
+			 exporting clubs needs version {}; export at a later block",
+			version, EXPORT_API_VERSION
+		))),
+		None => Err(sc_cli::Error::Input(format!(
+			"the runtime at this block does not implement the club runtime API, exporting \
+			 clubs needs version {}; export at a later block",
+			EXPORT_API_VERSION
+		))),
+	}
+}
+
+fn write_csv(out: &mut impl Write, clubs: &[ClubRecord]) -> io::Result<()> {
+	writeln!(out, "{}", CSV_HEADER)?;
+	for club in clubs {
+		if club.members.is_empty() && club.dependents.is_empty() {
+			writeln!(out, "{},{},{},,,,,,,", club.id, club.owner, club.price)?;
+		}
+		for member in &club.members {
+			writeln!(
+				out,
+				"{},{},{},{},{},{},{},{},{},",
+				club.id,
+				club.owner,
+				club.price,
+				member.account,
+				member.expires_at,
+				member.expires_at_ms,
+				member.lifetime,
+				member.suspended,
+				member.stake.map(|stake| stake.to_string()).unwrap_or_default()
+			)?;
+		}
+		for dependent in &club.dependents {
+			writeln!(
+				out,
+				"{},{},{},{},,,,,,{}",
+				club.id, club.owner, club.price, dependent.account, dependent.primary
+			)?;
+		}
+	}
+	Ok(())
+}
+
+/// Format milliseconds since the unix epoch as an RFC 3339 date in UTC.
+fn format_timestamp(ms: u64) -> String {
+	let secs = ms / 1000;
+	let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+	// Convert days since the epoch into a civil date, after Howard Hinnant's `civil_from_days`.
+	let z = days as i64 + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + i64::from(month <= 2);
+
+	format!(
+		"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+		year,
+		month,
+		day,
+		secs_of_day / 3_600,
+		secs_of_day % 3_600 / 60,
+		secs_of_day % 60
+	)
+}
+
+/// Read the clubs of a file written by `club export`.
+///
+/// Of the expiry columns only `expires_at_ms` is read; the date is for humans. Rows with a
+/// `primary` are dependents.
+fn read_csv(input: &str) -> Result<Vec<ClubRecord>, String> {
+	let mut lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+	match lines.next() {
+		Some((_, header)) if header.trim() == CSV_HEADER => (),
+		_ => return Err(format!("expected the CSV header `{}`", CSV_HEADER)),
+	}
+
+	let mut clubs: Vec<ClubRecord> = Vec::new();
+	for (index, line) in lines {
+		let row = index + 1;
+		let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+		let [id, owner, price, account, expires_at, expires_at_ms, lifetime, suspended, stake, primary] =
+			fields[..]
+		else {
+			return Err(format!("row {}: expected {} columns", row, CSV_HEADER.split(',').count()))
+		};
+		let id: ClubId = id.parse().map_err(|e| format!("row {}: club_id: {}", row, e))?;
+		let price: u32 = price.parse().map_err(|e| format!("row {}: price: {}", row, e))?;
+
+		let club = match clubs.iter_mut().find(|club| club.id == id) {
+			Some(club) if club.owner != owner || club.price != price =>
+				return Err(format!("row {}: club {} has another owner or price above", row, id)),
+			Some(club) => club,
+			None => {
+				clubs.push(ClubRecord {
+					id,
+					owner: owner.into(),
+					price,
+					members: Vec::new(),
+					dependents: Vec::new(),
+				});
+				clubs.last_mut().expect("just pushed; qed")
+			},
+		};
+		let flag = |value: &str, column: &str| match value {
+			"" | "false" => Ok(false),
+			"true" => Ok(true),
+			_ => Err(format!("row {}: {}: expected true or false", row, column)),
+		};
+		if account.is_empty() {
+			continue
+		} else if !primary.is_empty() {
+			club.dependents
+				.push(DependentRecord { account: account.into(), primary: primary.into() });
+		} else {
+			club.members.push(MemberRecord {
+				account: account.into(),
+				expires_at: expires_at.into(),
+				expires_at_ms: expires_at_ms
+					.parse()
+					.map_err(|e| format!("row {}: expires_at_ms: {}", row, e))?,
+				lifetime: flag(lifetime, "lifetime")?,
+				suspended: flag(suspended, "suspended")?,
+				stake: match stake {
+					"" => None,
+					stake => Some(stake.parse().map_err(|e| format!("row {}: stake: {}", row, e))?),
+				},
+			});
+		}
+	}
+	Ok(clubs)
+}
+
+/// The `club import-into-spec` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ImportIntoSpecCmd {
+	/// The file of clubs to import, as written by `club export`.
+	#[arg(long, value_name = "PATH")]
+	pub input: PathBuf,
+
+	/// The format of the input file.
+	#[arg(long, value_enum, default_value_t = Format::Json)]
+	pub format: Format,
+
+	/// Write the chain spec in raw format, even if the chain spec to import into is not raw.
+	#[arg(long)]
+	pub raw: bool,
+
+	/// The file to write the chain spec to. Defaults to stdout.
+	#[arg(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+}
+
+impl ImportIntoSpecCmd {
+	/// Add the clubs of the input file to the genesis of `spec`.
+	///
+	/// The clubs are checked against the constraints of the pallet together with the clubs
+	/// already in the spec. Expiries must be after the current time, and no later than can be
+	/// paid for in advance at genesis.
+	pub fn run(&self, spec: Box<dyn ChainSpec>) -> sc_cli::Result<()> {
+		let mut warnings = Vec::new();
+		let clubs = read_input(&self.input, self.format)?
+			.into_iter()
+			.map(|club| club.into_genesis(&mut warnings))
+			.collect::<Result<Vec<_>, _>>()?;
+		for warning in warnings {
+			eprintln!("warning: {}", warning);
+		}
+		let now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|elapsed| elapsed.as_millis() as u64)
+			.unwrap_or_default();
+
+		let mut json: Value =
+			serde_json::from_str(&spec.as_json(false)?).map_err(|e| e.to_string())?;
+		let genesis = json.get_mut("genesis").ok_or("the chain spec has no genesis")?;
+		if let Some(top) = genesis.pointer_mut("/raw/top").and_then(Value::as_object_mut) {
+			import_into_raw(top, clubs, now)?;
+		} else if genesis.pointer("/runtime/templateModule").is_some() {
+			let balances = genesis.pointer("/runtime/balances/balances").cloned();
+			check_owners_endowed(balances.unwrap_or_default(), &clubs)?;
+			import_into_config(&mut genesis["runtime"]["templateModule"], clubs, now)?;
+		} else {
+			return Err("the chain spec has no genesis of the club pallet".into())
+		}
+
+		let mut spec = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+		if self.raw {
+			spec =
+				crate::chain_spec::ChainSpec::from_json_bytes(spec.into_bytes())?.as_json(true)?;
+		}
+		match &self.output {
+			Some(path) => fs::write(path, spec)?,
+			None => println!("{}", spec),
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ImportIntoSpecCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+fn read_input(path: &Path, format: Format) -> Result<Vec<ClubRecord>, String> {
+	let input = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+	match format {
+		Format::Json => serde_json::from_str(&input).map_err(|e| e.to_string()),
+		Format::Csv => read_csv(&input),
+	}
+	.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// The maximum number of members of a club in the runtime.
+fn max_members() -> u32 {
+	<<Runtime as pallet_club::Config>::MaxMembers as Get<u32>>::get()
+}
+
+/// The latest expiry a genesis club can have: the longest payable period after the zero
+/// timestamp of genesis.
+fn max_end() -> u64 {
+	pallet_club::Pallet::<Runtime>::max_period()
+}
+
+/// Check that the owners of `clubs` are endowed by the genesis `balances`: owners must exist to
+/// hold a club.
+fn check_owners_endowed(balances: Value, clubs: &[GenesisClub<AccountId>]) -> Result<(), String> {
+	let balances: Vec<(AccountId, Balance)> =
+		serde_json::from_value(balances).map_err(|e| format!("invalid balances: {}", e))?;
+	match clubs.iter().find(|club| balances.iter().all(|(who, _)| *who != club.owner)) {
+		Some(club) =>
+			Err(format!("invalid clubs: the owner of club {} is not endowed at genesis", club.id)),
+		None => Ok(()),
+	}
+}
+
+/// Add `clubs` to the genesis config of the club pallet.
+fn import_into_config(
+	config: &mut Value,
+	clubs: Vec<GenesisClub<AccountId>>,
+	now: u64,
+) -> Result<(), String> {
+	let mut all: Vec<GenesisClub<AccountId>> =
+		serde_json::from_value(config["clubs"].take()).map_err(|e| e.to_string())?;
+	all.extend(clubs);
+	validate_genesis_clubs(&all, now, max_end(), max_members())
+		.map_err(|e| format!("invalid clubs: {:?}", e))?;
+	config["clubs"] = serde_json::to_value(all).map_err(|e| e.to_string())?;
+	Ok(())
+}
+
+/// Add the storage of `clubs` to a raw genesis.
+///
+/// Raw storage can't be decoded back into clubs, so the clubs already in `top` are only checked
+/// for a clashing id. The storage is built on top of the existing one, in which the owners must
+/// have an account.
+fn import_into_raw(
+	top: &mut serde_json::Map<String, Value>,
+	clubs: Vec<GenesisClub<AccountId>>,
+	now: u64,
+) -> Result<(), String> {
+	validate_genesis_clubs(&clubs, now, max_end(), max_members())
+		.map_err(|e| format!("invalid clubs: {:?}", e))?;
+	let hex = |bytes: &[u8]| format!("0x{}", HexDisplay::from(&bytes));
+	if let Some(club) = clubs
+		.iter()
+		.find(|club| top.contains_key(&hex(&PalletStorage::<Runtime>::hashed_key_for(club.id))))
+	{
+		return Err(format!(
+			"invalid clubs: {:?}",
+			pallet_club::GenesisClubError::DuplicateClub(club.id)
+		))
+	}
+	if let Some(club) = clubs.iter().find(|club| {
+		!top.contains_key(&hex(&frame_system::Account::<Runtime>::hashed_key_for(&club.owner)))
+	}) {
+		return Err(format!("invalid clubs: the owner of club {} has no account", club.id))
+	}
+
+	let mut storage = Storage::default();
+	for (key, value) in top.iter() {
+		let value = value.as_str().ok_or_else(|| format!("{}: expected a hex string", key))?;
+		storage.top.insert(
+			bytes::from_hex(key).map_err(|e| format!("{}: {}", key, e))?,
+			bytes::from_hex(value).map_err(|e| format!("{}: {}", key, e))?,
+		);
+	}
+	GenesisBuild::<Runtime>::assimilate_storage(
+		&pallet_club::GenesisConfig::<Runtime> { clubs },
+		&mut storage,
+	)?;
+	*top = storage
+		.top
+		.iter()
+		.map(|(key, value)| (hex(key), Value::String(hex(value))))
+		.collect();
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::{Decode, Encode};
+	use node_template_runtime::Index;
+	use pallet_club::LifetimeMembers;
+
+	type AccountInfo =
+		frame_system::AccountInfo<Index, <Runtime as frame_system::Config>::AccountData>;
+
+	#[test]
+	fn round_trip_test() {
+		// 2023-11-14T22:13:20Z
+		let now = 1_700_000_000_000;
+		let [owner, staker, lifetime, paying, dependent] =
+			[1u8, 2, 3, 4, 5].map(|seed| AccountId::from([seed; 32]));
+		let member = |account: &AccountId, expires_at, lifetime| MemberInfo {
+			account: account.clone(),
+			expires_at,
+			lifetime,
+			suspended: false,
+			active: true,
+		};
+		let export = vec![ClubRecord {
+			id: 7,
+			owner: owner.to_ss58check(),
+			price: 10,
+			members: vec![
+				// A stake membership, ending when the member joined.
+				MemberRecord::new(&member(&staker, now - 1_000, false), Some(500)),
+				// A lifetime membership whose paid time ended.
+				MemberRecord::new(&member(&lifetime, now - 1_000, true), None),
+				MemberRecord::new(&member(&paying, now + 1_000, false), None),
+			],
+			dependents: vec![DependentRecord {
+				account: dependent.to_ss58check(),
+				primary: paying.to_ss58check(),
+			}],
+		}];
+
+		let json = serde_json::to_string(&export).unwrap();
+		assert_eq!(serde_json::from_str::<Vec<ClubRecord>>(&json).unwrap(), export);
+		let mut csv = Vec::new();
+		write_csv(&mut csv, &export).unwrap();
+		assert_eq!(read_csv(&String::from_utf8(csv).unwrap()).unwrap(), export);
+
+		let mut warnings = Vec::new();
+		let clubs = export
+			.into_iter()
+			.map(|club| club.into_genesis(&mut warnings))
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		assert_eq!(warnings.len(), 2);
+		assert!(warnings[0].contains(&staker.to_ss58check()));
+		assert!(warnings[1].contains(&dependent.to_ss58check()));
+
+		let balances = serde_json::json!([[paying.to_ss58check(), 1_000]]);
+		assert!(check_owners_endowed(balances, &clubs).is_err());
+		let balances = serde_json::json!([[owner.to_ss58check(), 1_000]]);
+		assert_eq!(check_owners_endowed(balances, &clubs), Ok(()));
+		let mut config = serde_json::json!({ "clubs": [] });
+		import_into_config(&mut config, clubs.clone(), now).unwrap();
+		let imported: Vec<GenesisClub<AccountId>> =
+			serde_json::from_value(config["clubs"].clone()).unwrap();
+		assert_eq!(
+			imported[0].members,
+			vec![(lifetime.clone(), now - 1_000), (paying, now + 1_000)]
+		);
+		assert_eq!(imported[0].lifetime, vec![lifetime.clone()]);
+
+		let hex = |bytes: &[u8]| format!("0x{}", HexDisplay::from(&bytes));
+		let mut top = serde_json::Map::new();
+		assert!(import_into_raw(&mut top, clubs.clone(), now).is_err());
+		let account = AccountInfo { providers: 1, ..Default::default() };
+		let account_key = frame_system::Account::<Runtime>::hashed_key_for(&owner);
+		top.insert(hex(&account_key), Value::String(hex(&account.encode())));
+		import_into_raw(&mut top, clubs, now).unwrap();
+		let key = LifetimeMembers::<Runtime>::hashed_key_for(7, &lifetime);
+		assert!(top.contains_key(&hex(&key)));
+
+		// The owner holds a consumer reference for the club.
+		let account = bytes::from_hex(top[&hex(&account_key)].as_str().unwrap()).unwrap();
+		let account = AccountInfo::decode(&mut &account[..]).unwrap();
+		assert_eq!(account.consumers, 1);
+	}
+
+	#[test]
+	fn export_api_version_test() {
+		assert!(ensure_export_supported(None).is_err());
+		let error = ensure_export_supported(Some(4)).unwrap_err().to_string();
+		assert!(error.contains("version 4") && error.contains("version 5"), "{}", error);
+		assert!(ensure_export_supported(Some(5)).is_ok());
+		assert!(ensure_export_supported(Some(6)).is_ok());
+	}
+}
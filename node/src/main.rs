@@ -6,6 +6,7 @@ mod chain_spec;
 mod service;
 mod benchmarking;
 mod cli;
+mod club;
 mod command;
 mod rpc;
 
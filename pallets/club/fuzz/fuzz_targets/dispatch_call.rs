@@ -0,0 +1,27 @@
+//! Decodes an origin and a call of the club pallet from arbitrary bytes and dispatches it against
+//! the mock runtime, with two clubs set up to call into. A panic or a broken invariant of the
+//! pallet is a bug.
+//!
+//! Run with `cargo fuzz run dispatch_call` from `pallets/club`.
+
+#![no_main]
+
+use codec::Decode;
+use libfuzzer_sys::fuzz_target;
+use pallet_club::{mock::*, Call};
+use sp_runtime::traits::Dispatchable;
+
+fuzz_target!(|data: &[u8]| {
+	let Ok((signer, call)) = <(Option<u64>, Call<Test>)>::decode(&mut &data[..]) else { return };
+
+	new_test_ext().execute_with(|| {
+		for (club_id, owner, member) in [(0, 56, 57), (1, 57, 58)] {
+			TemplateModule::create_club(RuntimeOrigin::root(), owner, club_id, 10).unwrap();
+			TemplateModule::add_member(RuntimeOrigin::signed(owner), club_id, member).unwrap();
+		}
+
+		let origin = signer.map_or_else(RuntimeOrigin::root, RuntimeOrigin::signed);
+		let _ = RuntimeCall::TemplateModule(call).dispatch(origin);
+		TemplateModule::do_try_state().unwrap();
+	});
+});
@@ -0,0 +1,490 @@
+//! RPC interface for the club pallet.
+//!
+//! Every method takes an optional block hash and reads the state of the best block when it is
+//! omitted. `club_subscribeEvents` streams the club events of new best or finalized blocks.
+//! Methods backed by runtime API calls added after its first version fail with
+//! [`Error::UnsupportedRuntime`] at blocks whose runtime lacks the call.
+//!
+//! [`ClubIndexApi`] serves the membership index kept in offchain storage by the pallet's
+//! offchain worker. It reflects the blocks the worker has processed, not a chosen block.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Decode};
+use futures::{future, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::{error::SubscriptionClosed, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::OffchainStorage;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_club::UpcomingExpiry;
+pub use pallet_club_runtime_api::{
+	ClubApi as ClubRuntimeApi, ClubEventInfo, ClubId, ClubInfo, MemberInfo, RenewalQuote,
+	MAX_MEMBERS_PAGE,
+};
+
+/// Selects the events streamed by `club_subscribeEvents`. Unset fields match any event.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter<AccountId> {
+	/// Only stream events of this club.
+	pub club_id: Option<ClubId>,
+	/// Only stream events in which this account takes part.
+	pub account: Option<AccountId>,
+}
+
+impl<AccountId: PartialEq> EventFilter<AccountId> {
+	/// Whether `event` passes the filter.
+	pub fn matches(&self, event: &ClubEventInfo<AccountId>) -> bool {
+		self.club_id.map_or(true, |club_id| event.club_id() == club_id) &&
+			self.account.as_ref().map_or(true, |who| event.involves(who))
+	}
+}
+
+/// The club events of a block that pass the filter of a subscription.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockEvents<BlockHash, AccountId> {
+	pub block: BlockHash,
+	pub events: Vec<ClubEventInfo<AccountId>>,
+}
+
+#[rpc(client, server)]
+pub trait ClubApi<BlockHash, AccountId, Moment> {
+	/// The details of a club, or `null` if it does not exist.
+	#[method(name = "club_getClub")]
+	fn get_club(
+		&self,
+		club_id: ClubId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ClubInfo<AccountId>>>;
+
+	/// Whether `who` is currently treated as a member of the club.
+	#[method(name = "club_isMember")]
+	fn is_member(&self, club_id: ClubId, who: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// When the membership of `who` ends, or `null` if `who` is not a member.
+	#[method(name = "club_membershipExpiry")]
+	fn membership_expiry(
+		&self,
+		club_id: ClubId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Moment>>;
+
+	/// Up to `limit` members of the club, starting after `cursor`. Pass the last account of a
+	/// page as the cursor of the next one. `limit` defaults to, and is capped at,
+	/// `MAX_MEMBERS_PAGE`.
+	#[method(name = "club_listMembers")]
+	fn list_members(
+		&self,
+		club_id: ClubId,
+		cursor: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<MemberInfo<AccountId, Moment>>>;
+
+	/// What renewing the membership of `who` by paying `expense` would cost, or `null` if the
+	/// renewal would be rejected. Needs version 2 of the club runtime API.
+	#[method(name = "club_quoteRenewal")]
+	fn quote_renewal(
+		&self,
+		club_id: ClubId,
+		who: AccountId,
+		expense: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RenewalQuote<Moment>>>;
+
+	/// Stream the club events of new best blocks, or of newly finalized blocks if `finalized`
+	/// is set. Blocks without events passing `filter` are skipped. Needs version 3 of the club
+	/// runtime API; the subscription is closed with an error at the first block whose events
+	/// cannot be read.
+	#[subscription(
+		name = "club_subscribeEvents" => "club_events",
+		unsubscribe = "club_unsubscribeEvents",
+		item = BlockEvents<BlockHash, AccountId>,
+	)]
+	fn subscribe_events(&self, filter: Option<EventFilter<AccountId>>, finalized: Option<bool>);
+}
+
+/// Provides RPC methods to query the state of clubs.
+pub struct Club<C, P> {
+	client: Arc<C>,
+	executor: SubscriptionTaskExecutor,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> Club<C, P> {
+	/// Create new `Club` with the given reference to the client and an executor for the tasks
+	/// feeding subscriptions.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The runtime does not implement the runtime API call behind the method.
+	UnsupportedRuntime,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::UnsupportedRuntime => 2,
+		}
+	}
+}
+
+/// The version of the club runtime API that added `quote_renewal`.
+const QUOTE_RENEWAL_API_VERSION: u32 = 2;
+/// The version of the club runtime API that added `events`.
+const EVENTS_API_VERSION: u32 = 3;
+
+fn runtime_error(e: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query club state.",
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+/// Check that the runtime at `at` implements at least version `required` of the club runtime
+/// API, which `method` needs.
+fn ensure_api_version<C, Block, AccountId, Moment>(
+	client: &C,
+	at: Block::Hash,
+	required: u32,
+	method: &str,
+) -> RpcResult<()>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: ClubRuntimeApi<Block, AccountId, Moment>,
+{
+	let version = client
+		.runtime_api()
+		.api_version::<dyn ClubRuntimeApi<Block, AccountId, Moment>>(at)
+		.map_err(runtime_error)?;
+	if version.map_or(false, |version| version >= required) {
+		return Ok(())
+	}
+	let found = match version {
+		Some(version) => format!("implements version {}", version),
+		None => "does not implement the club runtime API".into(),
+	};
+	Err(CallError::Custom(ErrorObject::owned(
+		Error::UnsupportedRuntime.into(),
+		"The runtime does not support this method.",
+		Some(format!(
+			"{} needs version {} of the club runtime API, the runtime at {:?} {}",
+			method, required, at, found
+		)),
+	))
+	.into())
+}
+
+impl<C, Block, AccountId, Moment> ClubApiServer<<Block as BlockT>::Hash, AccountId, Moment>
+	for Club<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: ClubRuntimeApi<Block, AccountId, Moment>,
+	AccountId: Codec + PartialEq + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Moment: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_club(
+		&self,
+		club_id: ClubId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ClubInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().club(at, club_id).map_err(runtime_error)
+	}
+
+	fn is_member(
+		&self,
+		club_id: ClubId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.is_active_member(at, club_id, who)
+			.map_err(runtime_error)
+	}
+
+	fn membership_expiry(
+		&self,
+		club_id: ClubId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Moment>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.membership_expiry(at, club_id, who)
+			.map_err(runtime_error)
+	}
+
+	fn list_members(
+		&self,
+		club_id: ClubId,
+		cursor: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<MemberInfo<AccountId, Moment>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let limit = limit.unwrap_or(MAX_MEMBERS_PAGE);
+		self.client
+			.runtime_api()
+			.members(at, club_id, cursor, limit)
+			.map_err(runtime_error)
+	}
+
+	fn quote_renewal(
+		&self,
+		club_id: ClubId,
+		who: AccountId,
+		expense: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RenewalQuote<Moment>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		ensure_api_version::<_, Block, AccountId, Moment>(
+			&*self.client,
+			at,
+			QUOTE_RENEWAL_API_VERSION,
+			"club_quoteRenewal",
+		)?;
+		self.client
+			.runtime_api()
+			.quote_renewal(at, club_id, who, expense)
+			.map_err(runtime_error)
+	}
+
+	fn subscribe_events(
+		&self,
+		mut sink: SubscriptionSink,
+		filter: Option<EventFilter<AccountId>>,
+		finalized: Option<bool>,
+	) -> SubscriptionResult {
+		let filter = filter.unwrap_or_default();
+		let finalized = finalized.unwrap_or(false);
+		let info = self.client.info();
+		let head = if finalized { info.finalized_hash } else { info.best_hash };
+		if let Err(e) = ensure_api_version::<_, Block, AccountId, Moment>(
+			&*self.client,
+			head,
+			EVENTS_API_VERSION,
+			"club_subscribeEvents",
+		) {
+			let _ = sink.reject(e);
+			return Ok(())
+		}
+
+		let hashes = if finalized {
+			self.client.finality_notification_stream().map(|block| block.hash).boxed()
+		} else {
+			self.client
+				.import_notification_stream()
+				.filter(|block| future::ready(block.is_new_best))
+				.map(|block| block.hash)
+				.boxed()
+		};
+
+		let client = self.client.clone();
+		let stream = hashes
+			.filter_map(move |block| {
+				// The runtime may have been downgraded, and the state of the block pruned, since
+				// the subscription started. Either ends the subscription rather than dropping
+				// the events of the block unnoticed.
+				let events = ensure_api_version::<_, Block, AccountId, Moment>(
+					&*client,
+					block,
+					EVENTS_API_VERSION,
+					"club_subscribeEvents",
+				)
+				.and_then(|()| client.runtime_api().events(block).map_err(runtime_error));
+				future::ready(match events {
+					Ok(events) => {
+						let events: Vec<_> =
+							events.into_iter().filter(|event| filter.matches(event)).collect();
+						(!events.is_empty()).then(|| Ok(BlockEvents { block, events }))
+					},
+					Err(e) => Some(Err(e)),
+				})
+			})
+			.boxed();
+
+		let fut = async move {
+			match sink.pipe_from_try_stream(stream).await {
+				SubscriptionClosed::Success => {
+					sink.close(SubscriptionClosed::Success);
+				},
+				SubscriptionClosed::RemotePeerAborted => (),
+				SubscriptionClosed::Failed(e) => {
+					sink.close(e);
+				},
+			}
+		};
+		self.executor.spawn("club-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
+
+#[rpc(client, server)]
+pub trait ClubIndexApi<AccountId, Moment> {
+	/// The accounts registered with the club, dependents included, according to the index.
+	#[method(name = "club_indexedMembers")]
+	fn indexed_members(&self, club_id: ClubId) -> RpcResult<Vec<AccountId>>;
+
+	/// The clubs of `who` according to the index.
+	#[method(name = "club_indexedClubsOf")]
+	fn indexed_clubs_of(&self, who: AccountId) -> RpcResult<Vec<ClubId>>;
+
+	/// The upcoming membership expiries, soonest first, up to `until` if given.
+	#[method(name = "club_upcomingExpiries")]
+	fn upcoming_expiries(
+		&self,
+		until: Option<Moment>,
+	) -> RpcResult<Vec<UpcomingExpiry<AccountId, Moment>>>;
+}
+
+/// Provides RPC methods to query the offchain membership index.
+pub struct ClubIndex<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> ClubIndex<S> {
+	/// Create new `ClubIndex` reading from the node's offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	fn get<V: Decode + Default>(&self, key: &[u8]) -> RpcResult<V> {
+		match self.storage.get(sp_offchain::STORAGE_PREFIX, key) {
+			Some(value) => V::decode(&mut &value[..]).map_err(runtime_error),
+			None => Ok(V::default()),
+		}
+	}
+}
+
+impl<S, AccountId, Moment> ClubIndexApiServer<AccountId, Moment> for ClubIndex<S>
+where
+	S: OffchainStorage + 'static,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Moment: Codec + PartialOrd + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn indexed_members(&self, club_id: ClubId) -> RpcResult<Vec<AccountId>> {
+		self.get(&pallet_club::members_key(club_id))
+	}
+
+	fn indexed_clubs_of(&self, who: AccountId) -> RpcResult<Vec<ClubId>> {
+		self.get(&pallet_club::clubs_key(&who))
+	}
+
+	fn upcoming_expiries(
+		&self,
+		until: Option<Moment>,
+	) -> RpcResult<Vec<UpcomingExpiry<AccountId, Moment>>> {
+		let mut expiries: Vec<UpcomingExpiry<AccountId, Moment>> =
+			self.get(pallet_club::EXPIRIES_KEY)?;
+		if let Some(until) = until {
+			expiries.retain(|entry| entry.expires_at <= until);
+		}
+		Ok(expiries)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	#[test]
+	fn event_filter_test() {
+		let created = ClubEventInfo::<u64>::ClubCreated { club_id: 1 };
+		let added = ClubEventInfo::MemberAdded { club_id: 1, member: 57 };
+		let new_owner = ClubEventInfo::NewOwner { club_id: 2, new_owner: 56 };
+		let paid = ClubEventInfo::MembershipPaid { club_id: 2, payer: 58, member: 57 };
+		let events = [created, added, new_owner, paid];
+		let matching = |filter: EventFilter<u64>| {
+			events.iter().map(|event| filter.matches(event)).collect::<Vec<_>>()
+		};
+
+		assert_eq!(matching(EventFilter::default()), [true, true, true, true]);
+		assert_eq!(
+			matching(EventFilter { club_id: Some(1), account: None }),
+			[true, true, false, false]
+		);
+		// Payers and members both take part in a payment; nobody takes part in a creation.
+		assert_eq!(
+			matching(EventFilter { club_id: None, account: Some(57) }),
+			[false, true, false, true]
+		);
+		assert_eq!(
+			matching(EventFilter { club_id: None, account: Some(58) }),
+			[false, false, false, true]
+		);
+		assert_eq!(
+			matching(EventFilter { club_id: Some(2), account: Some(57) }),
+			[false, false, false, true]
+		);
+		assert_eq!(
+			matching(EventFilter { club_id: Some(3), account: None }),
+			[false, false, false, false]
+		);
+	}
+
+	#[test]
+	fn club_index_test() {
+		let mut storage = InMemOffchainStorage::default();
+		let mut set = |key: &[u8], value: Vec<u8>| {
+			storage.set(sp_offchain::STORAGE_PREFIX, key, &value);
+		};
+		set(&pallet_club::members_key(5), vec![57u64, 58].encode());
+		set(&pallet_club::clubs_key(&57u64), vec![5u32].encode());
+		let expiry = |expires_at, member| UpcomingExpiry { expires_at, club_id: 5, member };
+		set(pallet_club::EXPIRIES_KEY, vec![expiry(10u64, 57u64), expiry(20, 58)].encode());
+		// Not a list of accounts.
+		set(&pallet_club::members_key(6), vec![0xff]);
+		let index = ClubIndex::new(storage);
+		let members = |club_id| ClubIndexApiServer::<u64, u64>::indexed_members(&index, club_id);
+		let clubs_of = |who| ClubIndexApiServer::<u64, u64>::indexed_clubs_of(&index, who);
+		let expiries = |until| ClubIndexApiServer::<u64, u64>::upcoming_expiries(&index, until);
+
+		assert_eq!(members(5).unwrap(), [57, 58]);
+		assert_eq!(clubs_of(57).unwrap(), [5]);
+		// Missing entries read as empty.
+		assert!(members(7).unwrap().is_empty());
+		assert!(clubs_of(58).unwrap().is_empty());
+		assert!(members(6).is_err());
+
+		assert_eq!(expiries(None).unwrap(), [expiry(10, 57), expiry(20, 58)]);
+		assert_eq!(expiries(Some(19)).unwrap(), [expiry(10, 57)]);
+		assert!(expiries(Some(9)).unwrap().is_empty());
+	}
+}
@@ -0,0 +1,71 @@
+//! Runtime API definition for the club pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_club::{
+	v1, ClubEventInfo, ClubId, ClubInfo, MemberInfo, RenewalQuote, MAX_MEMBERS_PAGE,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Read access to clubs and their members, so that clients do not have to decode raw
+	/// storage.
+	///
+	/// Changes to the returned types go into a new version module of `pallet_club` and come
+	/// with a new `api_version`, as do new methods. Clients should check the version before
+	/// calling a method added after version 1:
+	///
+	/// - version 2 added `quote_renewal`,
+	/// - version 3 added `events`,
+	/// - version 4 added `club_ids`,
+	/// - version 5 added `stakes` and `dependents`.
+	#[api_version(5)]
+	pub trait ClubApi<AccountId, Moment>
+	where
+		AccountId: Codec,
+		Moment: Codec,
+	{
+		/// The ids of all clubs, sorted.
+		#[api_version(4)]
+		fn club_ids() -> Vec<ClubId>;
+
+		/// The details of a club, or `None` if it does not exist.
+		fn club(club_id: ClubId) -> Option<ClubInfo<AccountId>>;
+
+		/// Whether `who` is currently treated as a member of the club.
+		fn is_active_member(club_id: ClubId, who: AccountId) -> bool;
+
+		/// When the membership of `who` ends, or `None` if `who` is not a member.
+		fn membership_expiry(club_id: ClubId, who: AccountId) -> Option<Moment>;
+
+		/// Up to `limit` members of the club, starting after `cursor`. `limit` is capped at
+		/// `MAX_MEMBERS_PAGE`.
+		fn members(
+			club_id: ClubId,
+			cursor: Option<AccountId>,
+			limit: u32,
+		) -> Vec<MemberInfo<AccountId, Moment>>;
+
+		/// The clubs `who` belongs to.
+		fn clubs_of(who: AccountId) -> Vec<ClubId>;
+
+		/// The members of the club in stake mode with the amounts they staked, in account order.
+		#[api_version(5)]
+		fn stakes(club_id: ClubId) -> Vec<(AccountId, u32)>;
+
+		/// The dependents of the club with their primary members, in dependent order.
+		#[api_version(5)]
+		fn dependents(club_id: ClubId) -> Vec<(AccountId, AccountId)>;
+
+		/// What renewing the membership of `who` by paying `expense` would cost, or `None` if
+		/// the renewal would be rejected.
+		#[api_version(2)]
+		fn quote_renewal(club_id: ClubId, who: AccountId, expense: u32) -> Option<RenewalQuote<Moment>>;
+
+		/// The club events deposited in the block.
+		#[api_version(3)]
+		fn events() -> Vec<ClubEventInfo<AccountId>>;
+	}
+}
@@ -0,0 +1,221 @@
+//! Types and queries backing the `ClubApi` runtime API.
+//!
+//! The types returned to clients live in versioned modules. A change to their layout goes into
+//! a new module together with a new version of the runtime API, so that clients can still decode
+//! the answers of older runtimes.
+
+use crate::{
+	ClubId, Config, Dependents, Event, LifetimeMembers, Pallet, PalletStorage, Stakes, Suspended,
+};
+use core::ops::Bound;
+use sp_std::vec::Vec;
+
+/// Version 1 of the runtime API types.
+pub mod v1 {
+	use crate::ClubId;
+	use codec::{Decode, Encode};
+	use frame_support::RuntimeDebug;
+	use scale_info::TypeInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct ClubInfo<AccountId> {
+		pub owner: AccountId,
+		pub annual_expenses: u32,
+		/// The number of paying members, suspended and expired ones included.
+		pub member_count: u32,
+		/// The account holding the club's funds.
+		pub treasury: AccountId,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct MemberInfo<AccountId, Moment> {
+		pub account: AccountId,
+		/// The end of the paid membership.
+		pub expires_at: Moment,
+		pub lifetime: bool,
+		pub suspended: bool,
+		/// Whether the account is currently treated as a member.
+		pub active: bool,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct RenewalQuote<Moment> {
+		/// The amount charged for the renewal.
+		pub price: u32,
+		/// The end of the membership after the renewal.
+		pub expires_at: Moment,
+	}
+
+	/// A club event of interest to clients.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub enum ClubEventInfo<AccountId> {
+		ClubCreated {
+			club_id: ClubId,
+		},
+		MemberAdded {
+			club_id: ClubId,
+			member: AccountId,
+		},
+		NewOwner {
+			club_id: ClubId,
+			new_owner: AccountId,
+		},
+		/// `payer` paid for the membership of `member`.
+		MembershipPaid {
+			club_id: ClubId,
+			payer: AccountId,
+			member: AccountId,
+		},
+	}
+
+	impl<AccountId: PartialEq> ClubEventInfo<AccountId> {
+		pub fn club_id(&self) -> ClubId {
+			match self {
+				Self::ClubCreated { club_id } |
+				Self::MemberAdded { club_id, .. } |
+				Self::NewOwner { club_id, .. } |
+				Self::MembershipPaid { club_id, .. } => *club_id,
+			}
+		}
+
+		/// Whether `who` takes part in the event.
+		pub fn involves(&self, who: &AccountId) -> bool {
+			match self {
+				Self::ClubCreated { .. } => false,
+				Self::MemberAdded { member, .. } => member == who,
+				Self::NewOwner { new_owner, .. } => new_owner == who,
+				Self::MembershipPaid { payer, member, .. } => payer == who || member == who,
+			}
+		}
+	}
+}
+
+pub use v1::{ClubEventInfo, ClubInfo, MemberInfo, RenewalQuote};
+
+/// The most members returned by a single call to [`Pallet::members_page`].
+pub const MAX_MEMBERS_PAGE: u32 = 100;
+
+impl<T: Config> Pallet<T> {
+	/// The ids of all clubs, sorted.
+	pub fn club_ids() -> Vec<ClubId> {
+		let mut ids: Vec<ClubId> = PalletStorage::<T>::iter_keys().collect();
+		ids.sort();
+		ids
+	}
+
+	/// The details of a club, if it exists.
+	pub fn club_info(club_id: ClubId) -> Option<ClubInfo<T::AccountId>> {
+		let club = PalletStorage::<T>::get(club_id)?;
+		Some(ClubInfo {
+			owner: club.owner,
+			annual_expenses: club.annual_expenses,
+			member_count: club.members.len() as u32,
+			treasury: Self::treasury_account(club_id),
+		})
+	}
+
+	/// Up to `limit` paying members of the club in account order, starting after `cursor`.
+	///
+	/// `limit` is capped at [`MAX_MEMBERS_PAGE`]. Pass the last account of a page as the cursor
+	/// of the next one.
+	pub fn members_page(
+		club_id: ClubId,
+		cursor: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<MemberInfo<T::AccountId, T::Moment>> {
+		let Some(club) = PalletStorage::<T>::get(club_id) else { return Vec::new() };
+		let start = cursor.map_or(Bound::Unbounded, Bound::Excluded);
+		club.members
+			.range((start, Bound::Unbounded))
+			.take(limit.min(MAX_MEMBERS_PAGE) as usize)
+			.map(|(account, expires_at)| MemberInfo {
+				account: account.clone(),
+				expires_at: *expires_at,
+				lifetime: Self::is_lifetime_member(club_id, account),
+				suspended: Suspended::<T>::contains_key(club_id, account),
+				active: Self::is_active_member(club_id, account),
+			})
+			.collect()
+	}
+
+	/// The members of the club in stake mode with the amounts they staked, in account order.
+	pub fn club_stakes(club_id: ClubId) -> Vec<(T::AccountId, u32)> {
+		let mut stakes: Vec<(T::AccountId, u32)> = Stakes::<T>::iter_prefix(club_id)
+			.map(|(who, stake)| (who, stake.amount))
+			.collect();
+		stakes.sort_by(|a, b| a.0.cmp(&b.0));
+		stakes
+	}
+
+	/// The dependents of the club with their primary members, in dependent order.
+	pub fn club_dependents(club_id: ClubId) -> Vec<(T::AccountId, T::AccountId)> {
+		let mut dependents: Vec<(T::AccountId, T::AccountId)> =
+			Dependents::<T>::iter_prefix(club_id).collect();
+		dependents.sort_by(|a, b| a.0.cmp(&b.0));
+		dependents
+	}
+
+	/// The clubs `who` belongs to, as a paying member or as a dependent, sorted.
+	pub fn clubs_of(who: &T::AccountId) -> Vec<ClubId> {
+		let mut clubs: Vec<ClubId> = PalletStorage::<T>::iter()
+			.filter(|(club_id, club)| {
+				club.members.contains_key(who) || Dependents::<T>::contains_key(club_id, who)
+			})
+			.map(|(club_id, _)| club_id)
+			.collect();
+		clubs.sort();
+		clubs
+	}
+
+	/// What renewing the membership of `who` by paying `expense` would cost, or `None` if the
+	/// renewal would be rejected.
+	pub fn quote_renewal(
+		club_id: ClubId,
+		who: &T::AccountId,
+		expense: u32,
+	) -> Option<RenewalQuote<T::Moment>> {
+		let club = PalletStorage::<T>::get(club_id)?;
+		let expires_at = *club.members.get(who)?;
+		if Suspended::<T>::contains_key(club_id, who) ||
+			LifetimeMembers::<T>::contains_key(club_id, who)
+		{
+			return None
+		}
+		if expense < club.annual_expenses {
+			return None
+		}
+		let expires_at = Self::renewed_end(expires_at, club.annual_expenses, expense)?;
+		Some(RenewalQuote {
+			price: Self::renewal_charge(club.annual_expenses, expense),
+			expires_at,
+		})
+	}
+
+	/// The client-facing form of `event`, if it is one clients are interested in.
+	pub fn event_info(event: Event<T>) -> Option<ClubEventInfo<T::AccountId>> {
+		Some(match event {
+			Event::ClubCreated { club_id } => ClubEventInfo::ClubCreated { club_id },
+			Event::MemberAdded { member, club_id } =>
+				ClubEventInfo::MemberAdded { club_id, member },
+			Event::NewOwner { new_owner, club_id } =>
+				ClubEventInfo::NewOwner { club_id, new_owner },
+			Event::MembershipExpencesPayed { member, club_id } =>
+				ClubEventInfo::MembershipPaid { club_id, payer: member.clone(), member },
+			Event::MembershipGifted { payer, beneficiary, club_id, .. } =>
+				ClubEventInfo::MembershipPaid { club_id, payer, member: beneficiary },
+			Event::GroupMembershipPayed { primary, club_id } =>
+				ClubEventInfo::MembershipPaid { club_id, payer: primary.clone(), member: primary },
+			_ => return None,
+		})
+	}
+}
@@ -5,83 +5,682 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::v2::*;
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::{vec, vec::Vec};
+
+fn scheduled_event<T: Config>(club_id: ClubId) -> ClubEvent<T::Moment> {
+	ClubEvent {
+		club_id,
+		capacity: 10,
+		sold: 0,
+		member_price: 1,
+		guest_price: 2,
+		start: T::Moment::from(100u32),
+		revenue: 0,
+		status: ClubEventStatus::Scheduled,
+	}
+}
+
+/// An owner account that exists, so that payments of less than the existential deposit can be
+/// made to it.
+fn paid_owner<T: Config>() -> T::AccountId {
+	let owner = account("owner", 0, 0);
+	T::Currency::make_free_balance_be(&owner, T::Currency::minimum_balance());
+	owner
+}
+
+/// A club of `owner` with `m` members other than the whitelisted caller.
+fn club_with_members<T: Config>(owner: T::AccountId, m: u32) -> Club<T::AccountId, T::Moment> {
+	let members = (0..m).map(|i| (account("member", i, 0), T::Moment::from(89u32))).collect();
+	Club { owner, members, annual_expenses: 1u32 }
+}
+
+/// Make `primary` the primary member of a group with `d` dependents.
+fn group_with_dependents<T: Config>(
+	club_id: ClubId,
+	primary: &T::AccountId,
+	d: u32,
+) -> Vec<T::AccountId> {
+	let dependents: Vec<T::AccountId> = (0..d).map(|i| account("dependent", i, 0)).collect();
+	for dependent in &dependents {
+		Dependents::<T>::insert(club_id, dependent, primary);
+	}
+	Groups::<T>::insert(club_id, primary, BoundedVec::truncate_from(dependents.clone()));
+	dependents
+}
+
+/// A group tier allowing `MaxDependents` dependents.
+fn largest_tier<T: Config>() -> GroupTier {
+	GroupTier { annual_expenses: 3u32, max_dependents: T::MaxDependents::get() }
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn create_club() {
+	fn create_club(m: Linear<0, { T::MaxMembers::get() }>) {
 		let caller2: T::AccountId = whitelisted_caller();
+		frame_system::Pallet::<T>::inc_providers(&caller2);
+
+		// The club replaces one of `m` members.
+		let replaced_owner: T::AccountId = account("owner", 0, 0);
+		frame_system::Pallet::<T>::inc_providers(&replaced_owner);
+		frame_system::Pallet::<T>::inc_consumers_without_limit(&replaced_owner)
+			.expect("the owner has a provider");
+		PalletStorage::<T>::insert(5, club_with_members::<T>(replaced_owner, m));
+
 		#[extrinsic_call]
 		create_club(RawOrigin::Root, caller2, 5, 1u32);
 	}
 
 	#[benchmark]
-	fn add_member() {
+	fn add_member(m: Linear<0, { T::MaxMembers::get() - 1 }>) {
 		let member: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
+
+		#[extrinsic_call]
+		add_member(RawOrigin::Signed(owner), club_id, member);
+	}
+
+	#[benchmark]
+	fn transfer_ownership(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		frame_system::Pallet::<T>::inc_providers(&owner);
+		frame_system::Pallet::<T>::inc_providers(&new_owner);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		frame_system::Pallet::<T>::inc_consumers_without_limit(&owner)
+			.expect("the owner has a provider");
+
+		#[extrinsic_call]
+		transfer_ownership(RawOrigin::Signed(owner), new_owner, club_id);
+	}
+
+	#[benchmark]
+	fn set_annual_expense(m: Linear<0, { T::MaxMembers::get() }>) {
 		let owner: T::AccountId = whitelisted_caller();
 
 		let club_id = 5;
 
-		PalletStorage::<T>::insert(
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_annual_expense(RawOrigin::Signed(owner), club_id, 2u32);
+	}
+
+	#[benchmark]
+	fn pay_membership_expense(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let member: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let club_id = 5;
+
+		// Renewing at the individual price dissolves the member's group.
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(member.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		group_with_dependents::<T>(club_id, &member, d);
+		Trials::<T>::insert(club_id, &member, TrialStatus::Started);
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
+
+		#[extrinsic_call]
+		pay_membership_expense(RawOrigin::Signed(member), club_id, 2u32);
+	}
+
+	#[benchmark]
+	fn suspend_member(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
+		let club_id = 5;
+
+		// The member is the first of the club's members.
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		group_with_dependents::<T>(club_id, &member, d);
+
+		#[extrinsic_call]
+		suspend_member(RawOrigin::Signed(owner), club_id, member);
+	}
+
+	#[benchmark]
+	fn unsuspend_member(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
+		let club_id = 5;
+
+		// The member is the first of the club's members.
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		group_with_dependents::<T>(club_id, &member, d);
+		Suspended::<T>::insert(club_id, &member, ());
+
+		#[extrinsic_call]
+		unsuspend_member(RawOrigin::Signed(owner), club_id, member);
+	}
+
+	#[benchmark]
+	fn ban_account(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("banned", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner.clone(), m - 1);
+		club.members.insert(member.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		group_with_dependents::<T>(club_id, &member, d);
+		Suspended::<T>::insert(club_id, &member, ());
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
+		T::Currency::reserve(&member, 100u32.into()).expect("the member is funded");
+		Stakes::<T>::insert(
 			club_id,
-			Club { owner: owner.clone(), members: Default::default(), annual_expenses: 1u32 },
+			&member,
+			StakeInfo { amount: 100u32, cooldown: T::Moment::from(10u32), unlock_at: None },
 		);
 
 		#[extrinsic_call]
-		add_member(RawOrigin::Signed(owner), club_id, member);
+		ban_account(RawOrigin::Signed(owner), club_id, member, Some(T::Hash::default()));
+	}
+
+	#[benchmark]
+	fn unban_account(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		Bans::<T>::insert(club_id, &member, Option::<T::Hash>::None);
+
+		#[extrinsic_call]
+		unban_account(RawOrigin::Signed(owner), club_id, member);
 	}
 
 	#[benchmark]
-	fn transfer_ownership() {
+	fn set_admission_policy(m: Linear<0, { T::MaxMembers::get() }>) {
 		let owner: T::AccountId = whitelisted_caller();
-		let new_owner: T::AccountId = whitelisted_caller();
 		let club_id = 5;
 
-		PalletStorage::<T>::insert(
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_admission_policy(RawOrigin::Signed(owner), club_id, AdmissionPolicy::Open);
+	}
+
+	#[benchmark]
+	fn gift_membership(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let payer: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let club_id = 5;
+
+		// The beneficiary renews a group membership, which reads the group and its tier.
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(beneficiary.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		GroupTiers::<T>::insert(club_id, largest_tier::<T>());
+		group_with_dependents::<T>(club_id, &beneficiary, d);
+		T::Currency::make_free_balance_be(&payer, 1_000u32.into());
+
+		#[extrinsic_call]
+		gift_membership(RawOrigin::Signed(payer), club_id, beneficiary, 6u32);
+	}
+
+	#[benchmark]
+	fn set_group_tier(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_group_tier(
+			RawOrigin::Signed(owner),
 			club_id,
-			Club { owner: owner.clone(), members: Default::default(), annual_expenses: 1u32 },
+			Some(GroupTier { annual_expenses: 3u32, max_dependents: 4 }),
 		);
+	}
+
+	#[benchmark]
+	fn pay_group_membership(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let member: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(member.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		GroupTiers::<T>::insert(club_id, largest_tier::<T>());
+		group_with_dependents::<T>(club_id, &member, d);
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
 
 		#[extrinsic_call]
-		transfer_ownership(RawOrigin::Signed(owner), new_owner, club_id);
+		pay_group_membership(RawOrigin::Signed(member), club_id, 6u32);
 	}
 
 	#[benchmark]
-	fn set_annual_expense() {
+	fn add_dependent(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() - 1 }>,
+	) {
+		let primary: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let dependent: T::AccountId = account("new_dependent", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(primary.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		GroupTiers::<T>::insert(club_id, largest_tier::<T>());
+		group_with_dependents::<T>(club_id, &primary, d);
+
+		#[extrinsic_call]
+		add_dependent(RawOrigin::Signed(primary), club_id, dependent);
+	}
+
+	#[benchmark]
+	fn remove_dependent(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<1, { T::MaxDependents::get() }>,
+	) {
+		let primary: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(primary.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		let dependent = group_with_dependents::<T>(club_id, &primary, d)
+			.pop()
+			.expect("the group has at least one dependent");
+
+		#[extrinsic_call]
+		remove_dependent(RawOrigin::Signed(primary), club_id, dependent);
+	}
+
+	#[benchmark]
+	fn set_trial_length(m: Linear<0, { T::MaxMembers::get() }>) {
 		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
 
+		#[extrinsic_call]
+		set_trial_length(RawOrigin::Signed(owner), club_id, Some(T::Moment::from(100u32)));
+	}
+
+	#[benchmark]
+	fn start_trial(m: Linear<0, { T::MaxMembers::get() - 1 }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
 		let club_id = 5;
 
-		PalletStorage::<T>::insert(
-			club_id,
-			Club { owner: owner.clone(), members: Default::default(), annual_expenses: 1u32 },
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner, m));
+		TrialLengths::<T>::insert(club_id, T::Moment::from(100u32));
+
+		#[extrinsic_call]
+		start_trial(RawOrigin::Signed(caller), club_id);
+	}
+
+	#[benchmark]
+	fn set_lifetime_price(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_lifetime_price(RawOrigin::Signed(owner), club_id, Some(50u32));
+	}
+
+	#[benchmark]
+	fn pay_lifetime_membership(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let member: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(member.clone(), T::Moment::from(89u32));
+		PalletStorage::<T>::insert(club_id, club);
+		group_with_dependents::<T>(club_id, &member, d);
+		LifetimePrices::<T>::insert(club_id, 50u32);
+		Trials::<T>::insert(club_id, &member, TrialStatus::Started);
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
+
+		#[extrinsic_call]
+		pay_lifetime_membership(RawOrigin::Signed(member), club_id);
+	}
+
+	#[benchmark]
+	fn create_event(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		create_event(RawOrigin::Signed(owner), club_id, 10, 1u32, 2u32, T::Moment::from(100u32));
+	}
+
+	#[benchmark]
+	fn buy_ticket(m: Linear<1, { T::MaxMembers::get() }>) {
+		let buyer: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(buyer.clone(), T::Moment::from(200u32));
+		PalletStorage::<T>::insert(club_id, club);
+		ClubEvents::<T>::insert(0, scheduled_event::<T>(club_id));
+		T::Currency::make_free_balance_be(&buyer, 1_000u32.into());
+
+		#[extrinsic_call]
+		buy_ticket(RawOrigin::Signed(buyer), 0);
+	}
+
+	#[benchmark]
+	fn check_in(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let holder: T::AccountId = account("holder", 0, 0);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		ClubEvents::<T>::insert(0, scheduled_event::<T>(club_id));
+		Tickets::<T>::insert(0, &holder, Ticket { price: 2u32, checked_in: false });
+
+		#[extrinsic_call]
+		check_in(RawOrigin::Signed(owner), 0, holder);
+	}
+
+	#[benchmark]
+	fn cancel_event(
+		t: Linear<0, { T::MaxEventCapacity::get() }>,
+		m: Linear<0, { T::MaxMembers::get() }>,
+	) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		let mut event = scheduled_event::<T>(club_id);
+		event.capacity = t;
+		event.sold = t;
+		event.revenue = 2 * t;
+		ClubEvents::<T>::insert(0, event);
+		for i in 0..t {
+			let holder: T::AccountId = account("holder", i, 0);
+			Tickets::<T>::insert(0, &holder, Ticket { price: 2u32, checked_in: false });
+		}
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::treasury_account(club_id),
+			1_000_000u32.into(),
 		);
 
 		#[extrinsic_call]
-		set_annual_expense(RawOrigin::Signed(owner), club_id, 2u32);
+		cancel_event(RawOrigin::Signed(owner), 0);
+	}
+
+	#[benchmark]
+	fn settle_event(m: Linear<0, { T::MaxMembers::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		Payouts::<T>::insert(&owner, vec![account::<T::AccountId>("destination", 0, 0)]);
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner, m));
+		let mut event = scheduled_event::<T>(club_id);
+		event.start = T::Moment::from(0u32);
+		event.revenue = 20;
+		ClubEvents::<T>::insert(0, event);
+		T::Currency::make_free_balance_be(&Pallet::<T>::treasury_account(club_id), 1_000u32.into());
+
+		#[extrinsic_call]
+		settle_event(RawOrigin::Signed(caller), 0);
 	}
 
 	#[benchmark]
-	fn pay_membership_expense() {
+	fn pay_membership_with_points(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
 		let member: T::AccountId = whitelisted_caller();
+		let owner = paid_owner::<T>();
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(member.clone(), T::Moment::from(89u32));
+		club.annual_expenses = 2u32;
+		PalletStorage::<T>::insert(club_id, club);
+		group_with_dependents::<T>(club_id, &member, d);
+		Points::<T>::insert(club_id, &member, T::PointsPerDiscountUnit::get());
+		for _ in 0..T::MaxLedgerEntries::get() {
+			Pallet::<T>::record_points(club_id, &member, PointsReason::Renewal, 1);
+		}
+		T::Currency::make_free_balance_be(&member, 1_000u32.into());
+
+		#[extrinsic_call]
+		pay_membership_with_points(
+			RawOrigin::Signed(member),
+			club_id,
+			2u32,
+			T::PointsPerDiscountUnit::get(),
+		);
+	}
+
+	#[benchmark]
+	fn grant_points(m: Linear<0, { T::MaxMembers::get() }>) {
 		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		for _ in 0..T::MaxLedgerEntries::get() {
+			Pallet::<T>::record_points(club_id, &member, PointsReason::Renewal, 1);
+		}
+
+		#[extrinsic_call]
+		grant_points(RawOrigin::Signed(owner), club_id, member, 10u32, 1u8);
+	}
 
+	#[benchmark]
+	fn deduct_points(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
 		let club_id = 5;
 
-		let mut members = BTreeMap::new();
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+		Points::<T>::insert(club_id, &member, 10u32);
+		for _ in 0..T::MaxLedgerEntries::get() {
+			Pallet::<T>::record_points(club_id, &member, PointsReason::Renewal, 1);
+		}
 
-		members.insert(member.clone(), T::Moment::from(89u32));
+		#[extrinsic_call]
+		deduct_points(RawOrigin::Signed(owner), club_id, member, 10u32, 1u8);
+	}
 
-		PalletStorage::<T>::insert(
+	#[benchmark]
+	fn set_join_mode(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_join_mode(
+			RawOrigin::Signed(owner),
+			club_id,
+			JoinMode::Stake { amount: 100u32, cooldown: T::Moment::from(10u32) },
+		);
+	}
+
+	#[benchmark]
+	fn join_with_stake(m: Linear<0, { T::MaxMembers::get() - 1 }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner, m));
+		JoinModes::<T>::insert(
 			club_id,
-			Club { owner: owner.clone(), members, annual_expenses: 1u32 },
+			JoinMode::Stake { amount: 100u32, cooldown: T::Moment::from(10u32) },
 		);
+		T::Currency::make_free_balance_be(&caller, 1_000u32.into());
 
 		#[extrinsic_call]
-		pay_membership_expense(RawOrigin::Signed(member), club_id, 2u32);
+		join_with_stake(RawOrigin::Signed(caller), club_id);
+	}
+
+	#[benchmark]
+	fn unstake() {
+		let caller: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		Stakes::<T>::insert(
+			club_id,
+			&caller,
+			StakeInfo { amount: 100u32, cooldown: T::Moment::from(10u32), unlock_at: None },
+		);
+
+		#[extrinsic_call]
+		unstake(RawOrigin::Signed(caller), club_id);
+	}
+
+	#[benchmark]
+	fn withdraw_stake(
+		m: Linear<1, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxDependents::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(caller.clone(), T::Moment::from(0u32));
+		PalletStorage::<T>::insert(club_id, club);
+		group_with_dependents::<T>(club_id, &caller, d);
+		T::Currency::make_free_balance_be(&caller, 1_000u32.into());
+		T::Currency::reserve(&caller, 100u32.into()).expect("the caller is funded");
+		Stakes::<T>::insert(
+			club_id,
+			&caller,
+			StakeInfo {
+				amount: 100u32,
+				cooldown: T::Moment::from(10u32),
+				unlock_at: Some(T::Moment::from(0u32)),
+			},
+		);
+
+		#[extrinsic_call]
+		withdraw_stake(RawOrigin::Signed(caller), club_id);
+	}
+
+	#[benchmark]
+	fn set_fee_policy(m: Linear<0, { T::MaxMembers::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner.clone(), m));
+
+		#[extrinsic_call]
+		set_fee_policy(
+			RawOrigin::Signed(owner),
+			club_id,
+			Some(FeePolicy { discount: Percent::from_percent(50), scope: FeeScope::AllCalls }),
+		);
+	}
+
+	#[benchmark]
+	fn check_club_call(m: Linear<1, { T::MaxMembers::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		let mut club = club_with_members::<T>(owner, m - 1);
+		club.members.insert(caller.clone(), T::Moment::from(0u32));
+		PalletStorage::<T>::insert(club_id, club);
+		let call = Call::<T>::pay_membership_expense { club_id, expense: 1u32 };
+
+		#[block]
+		{
+			assert!(CheckClubCall::<T>::validate_club_call(&caller, &call).is_ok());
+		}
+	}
+	#[benchmark]
+	fn claim_refund() {
+		let holder: T::AccountId = whitelisted_caller();
+		let club_id = 5;
+
+		let mut event = scheduled_event::<T>(club_id);
+		event.sold = 1;
+		event.revenue = 2;
+		event.status = ClubEventStatus::Cancelled;
+		ClubEvents::<T>::insert(0, event);
+		Tickets::<T>::insert(0, &holder, Ticket { price: 2u32, checked_in: false });
+		PendingRefunds::<T>::insert(0, &holder, 2u32);
+		T::Currency::make_free_balance_be(&Pallet::<T>::treasury_account(club_id), 1_000u32.into());
+
+		#[extrinsic_call]
+		claim_refund(RawOrigin::Signed(holder), 0);
+	}
+
+	#[benchmark]
+	fn set_payout_destination() {
+		let owner: T::AccountId = whitelisted_caller();
+		let destination: T::AccountId = account("destination", 0, 0);
+		frame_system::Pallet::<T>::inc_providers(&destination);
+
+		// The previous destination is released.
+		let previous: T::AccountId = account("destination", 1, 0);
+		frame_system::Pallet::<T>::inc_providers(&previous);
+		frame_system::Pallet::<T>::inc_consumers_without_limit(&previous)
+			.expect("the destination has a provider");
+		Payouts::<T>::insert(&owner, vec![previous]);
+
+		#[extrinsic_call]
+		set_payout_destination(RawOrigin::Signed(owner), Some(destination));
+	}
+
+	#[benchmark]
+	fn active_members(
+		m: Linear<0, { T::MaxMembers::get() }>,
+		d: Linear<0, { T::MaxMembers::get() * T::MaxDependents::get() }>,
+	) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let club_id = 5;
+
+		PalletStorage::<T>::insert(club_id, club_with_members::<T>(owner, m));
+		// The dependents fill the groups of the members one after the other.
+		for i in 0..d {
+			let primary: T::AccountId = account("member", i / T::MaxDependents::get(), 0);
+			Dependents::<T>::insert(club_id, account::<T::AccountId>("dependent", i, 0), primary);
+		}
+
+		#[block]
+		{
+			Pallet::<T>::active_members(club_id);
+		}
 	}
 }
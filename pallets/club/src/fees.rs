@@ -0,0 +1,173 @@
+//! Sponsoring of transaction fees by clubs.
+//!
+//! [`SponsorClubFees`] is a signed extension in which the signer names a club to sponsor the
+//! transaction. If the signer is an active member and the club's [`FeePolicy`] covers the
+//! call, the discounted part of the fee is moved from the club's treasury to the signer before
+//! `ChargeTransactionPayment` withdraws the fee, and the sponsored part of any refund is moved
+//! back afterwards. The fee itself is computed by `pallet_transaction_payment` as usual, so
+//! `query_info` keeps reporting the full fee of the transaction.
+//!
+//! Only the [`sponsorable_balance`] of the treasury is used: ticket revenue and pending
+//! refunds held in [`Escrow`] are never spent on fees.
+//!
+//! Validation does not move any funds, so the transaction pool only accepts the transaction if
+//! the signer can afford the full fee. The discount is applied when the transaction is
+//! included.
+//!
+//! Signed extensions run their `post_dispatch` in the order of the `SignedExtra` tuple, so the
+//! sponsored part of the refund is reclaimed before `ChargeTransactionPayment` refunds the
+//! signer. The reclaim is best effort: it is skipped if the signer cannot pay it before the
+//! refund arrives, and the club then bears the sponsored part of the refund.
+//!
+//! [`FeePolicy`]: crate::FeePolicy
+//! [`sponsorable_balance`]: crate::Pallet::sponsorable_balance
+//! [`Escrow`]: crate::Escrow
+
+use crate::{BalanceOf, ClubId, Config, Event, FeePolicies, FeeScope, Pallet};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	sp_runtime::{
+		traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, Zero},
+		transaction_validity::TransactionValidityError,
+		DispatchResult, Percent, SaturatedConversion,
+	},
+	traits::{Currency, ExistenceRequirement::KeepAlive, IsSubType},
+};
+use scale_info::TypeInfo;
+
+/// Lets the club named by the signer pay part of the transaction fee.
+///
+/// Must be placed before `ChargeTransactionPayment` in the runtime's `SignedExtra`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct SponsorClubFees<T: Config + Send + Sync>(pub Option<ClubId>, PhantomData<T>);
+
+impl<T: Config + Send + Sync> SponsorClubFees<T> {
+	/// Ask `club_id` to sponsor the transaction, or pay the full fee with `None`.
+	pub fn from(club_id: Option<ClubId>) -> Self {
+		Self(club_id, PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for SponsorClubFees<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "SponsorClubFees({:?})", self.0)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> SponsorClubFees<T>
+where
+	T: Config + pallet_transaction_payment::Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<crate::Call<T>>,
+{
+	/// The full fee, the sponsored part of it and the discount, or `None` if the club does not
+	/// sponsor the transaction or cannot afford it.
+	fn sponsorship(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>, Percent)> {
+		let club_id = self.0?;
+		let policy = FeePolicies::<T>::get(club_id)?;
+		if policy.scope == FeeScope::ClubCalls && call.is_sub_type().is_none() {
+			return None
+		}
+		if !Pallet::<T>::is_active_member(club_id, who) {
+			return None
+		}
+
+		let fee: u128 =
+			pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero())
+				.saturated_into();
+		let fee: BalanceOf<T> = fee.saturated_into();
+		let sponsored = policy.discount.mul_floor(fee);
+		if sponsored.is_zero() || sponsored > Pallet::<T>::sponsorable_balance(club_id) {
+			return None
+		}
+		Some((fee, sponsored, policy.discount))
+	}
+}
+
+impl<T> SignedExtension for SponsorClubFees<T>
+where
+	T: Config + pallet_transaction_payment::Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<crate::Call<T>>,
+{
+	const IDENTIFIER: &'static str = "SponsorClubFees";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// The sponsored account, the sponsoring club, the full fee and the discount.
+	type Pre = Option<(T::AccountId, ClubId, BalanceOf<T>, Percent)>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let Some((fee, sponsored, discount)) = self.sponsorship(who, call, info, len) else {
+			return Ok(None)
+		};
+		let club_id = self.0.unwrap_or_default();
+		if T::Currency::transfer(&Pallet::<T>::treasury_account(club_id), who, sponsored, KeepAlive)
+			.is_err()
+		{
+			return Ok(None)
+		}
+		Pallet::<T>::deposit_event(Event::FeesSponsored {
+			club_id,
+			who: who.clone(),
+			amount: sponsored.saturated_into(),
+		});
+		Ok(Some((who.clone(), club_id, fee, discount)))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(Some((who, club_id, fee, discount))) = pre {
+			let actual_fee: u128 = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+				len as u32,
+				info,
+				post_info,
+				Zero::zero(),
+			)
+			.saturated_into();
+			let refunded = fee.saturating_sub(actual_fee.saturated_into());
+			let reclaimed = discount.mul_floor(refunded);
+			if !reclaimed.is_zero() {
+				// Best effort: this runs before `ChargeTransactionPayment` refunds the signer, so
+				// the signer may not be able to afford it yet, see the module docs.
+				let _ = T::Currency::transfer(
+					&who,
+					&Pallet::<T>::treasury_account(club_id),
+					reclaimed,
+					KeepAlive,
+				);
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,140 @@
+//! Invariants of the club pallet storage.
+//!
+//! They are checked by the `try_state` hook under `try-runtime`. The unit tests check them after
+//! each successful call and at the end of each test.
+
+use crate::{
+	ClubEventStatus, ClubEvents, Config, Dependents, Escrow, Groups, Pallet, PalletStorage,
+	Payouts, PendingRefunds, Tickets,
+};
+use frame_support::{ensure, sp_runtime::traits::Saturating, traits::Currency};
+use pallet_timestamp as timestamp;
+use sp_std::collections::btree_map::BTreeMap;
+
+impl<T: Config> Pallet<T> {
+	/// Check all invariants of the pallet storage.
+	pub fn do_try_state() -> Result<(), &'static str> {
+		Self::check_clubs()?;
+		Self::check_payouts()?;
+		Self::check_events()?;
+		Self::check_groups()
+	}
+
+	/// Every club owner exists and holds a consumer reference for each of its clubs, and no
+	/// membership ends more than `MAX_YEARS` from now.
+	fn check_clubs() -> Result<(), &'static str> {
+		let horizon = <timestamp::Pallet<T>>::get().saturating_add(Self::max_period());
+		let mut owned = BTreeMap::<T::AccountId, u32>::new();
+		for club in PalletStorage::<T>::iter_values() {
+			ensure!(
+				club.members.values().all(|end| *end <= horizon),
+				"a membership ends more than MAX_YEARS from now"
+			);
+			*owned.entry(club.owner).or_default() += 1;
+		}
+		for (owner, clubs) in owned {
+			ensure!(
+				frame_system::Pallet::<T>::account_exists(&owner),
+				"a club owner does not exist"
+			);
+			ensure!(
+				frame_system::Pallet::<T>::consumers(&owner) >= clubs,
+				"a club owner does not hold a consumer reference for each club"
+			);
+		}
+		Ok(())
+	}
+
+	/// Every entry of `Payouts` names a destination, and every destination exists and holds a
+	/// consumer reference for each entry naming it.
+	fn check_payouts() -> Result<(), &'static str> {
+		let mut named = BTreeMap::<T::AccountId, u32>::new();
+		for destinations in Payouts::<T>::iter_values() {
+			ensure!(!destinations.is_empty(), "a payout entry has no destination");
+			for destination in destinations {
+				*named.entry(destination).or_default() += 1;
+			}
+		}
+		for (destination, entries) in named {
+			ensure!(
+				frame_system::Pallet::<T>::account_exists(&destination),
+				"a payout destination does not exist"
+			);
+			ensure!(
+				frame_system::Pallet::<T>::consumers(&destination) >= entries,
+				"a payout destination does not hold a consumer reference for each entry"
+			);
+		}
+		Ok(())
+	}
+
+	/// The ticket counters of events match their tickets, and the treasury of each club holds
+	/// the revenue of its scheduled events, which may have to be refunded, and the pending
+	/// refunds of its cancelled events. `Escrow` records exactly these amounts.
+	fn check_events() -> Result<(), &'static str> {
+		let mut obligations = BTreeMap::new();
+		for (event_id, event) in ClubEvents::<T>::iter() {
+			let (mut sold, mut revenue) = (0u32, 0u32);
+			for (_, ticket) in Tickets::<T>::iter_prefix(event_id) {
+				sold += 1;
+				revenue = revenue.saturating_add(ticket.price);
+			}
+			ensure!(sold == event.sold, "the tickets sold do not match the tickets of an event");
+			let pending = PendingRefunds::<T>::iter_prefix_values(event_id)
+				.fold(0u32, |total, amount| total.saturating_add(amount));
+
+			match event.status {
+				ClubEventStatus::Scheduled => ensure!(
+					revenue == event.revenue && pending == 0,
+					"the revenue does not match the tickets of a scheduled event"
+				),
+				ClubEventStatus::Cancelled => ensure!(
+					pending == event.revenue,
+					"the revenue does not match the pending refunds of a cancelled event"
+				),
+				ClubEventStatus::Settled =>
+					ensure!(event.revenue == 0 && pending == 0, "a settled event holds revenue"),
+			}
+			let obligation: &mut u32 = obligations.entry(event.club_id).or_default();
+			*obligation = obligation.saturating_add(event.revenue);
+		}
+
+		for (club_id, escrow) in Escrow::<T>::iter() {
+			ensure!(
+				obligations.get(&club_id).copied().unwrap_or_default() == escrow,
+				"the escrow of a club does not match the revenue of its events"
+			);
+		}
+		for (club_id, obligation) in obligations {
+			ensure!(
+				Escrow::<T>::get(club_id) == obligation,
+				"the escrow of a club does not match the revenue of its events"
+			);
+			ensure!(
+				T::Currency::free_balance(&Self::treasury_account(club_id)) >= obligation.into(),
+				"a club treasury does not cover the refunds of its scheduled events"
+			);
+		}
+		Ok(())
+	}
+
+	/// The groups and the dependents agree with each other. The size of a group is not checked
+	/// against the club's group tier, which the owner can lower after the group filled up.
+	fn check_groups() -> Result<(), &'static str> {
+		let mut dependents = 0usize;
+		for (club_id, primary, group) in Groups::<T>::iter() {
+			for dependent in &group {
+				ensure!(
+					Dependents::<T>::get(club_id, dependent).as_ref() == Some(&primary),
+					"a dependent of a group is not recorded as such"
+				);
+			}
+			dependents += group.len();
+		}
+		ensure!(
+			Dependents::<T>::iter().count() == dependents,
+			"a dependent is not in the group of its primary member"
+		);
+		Ok(())
+	}
+}
@@ -1,20 +1,48 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::{Currency, ExistenceRequirement::AllowDeath};
+use frame_support::{
+	sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, Saturating, Zero},
+		Percent, SaturatedConversion,
+	},
+	traits::{Currency, ExistenceRequirement::AllowDeath, ReservableCurrency},
+	weights::Weight,
+	PalletId,
+};
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 use pallet_timestamp::{self as timestamp};
 
-#[cfg(test)]
-mod mock;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod mock;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod properties;
+
+pub mod api;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub use api::*;
+pub mod fees;
+pub use fees::*;
+#[cfg(any(feature = "try-runtime", feature = "fuzzing", test))]
+mod invariants;
+pub mod membership;
+pub use membership::*;
+pub mod migrations;
+pub mod offchain;
+pub use offchain::*;
+pub mod origin;
+pub use origin::*;
+pub mod validation;
+pub use validation::*;
+pub mod webhook;
+pub use webhook::*;
 pub mod weights;
 pub use weights::*;
 
@@ -23,12 +51,20 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::{StorageMap, *};
 	use frame_system::pallet_prelude::*;
-	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_std::{
+		collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+		vec,
+		vec::Vec,
+	};
 
 	pub type ClubId = u32;
 	pub type AccountId = u32;
+	pub type EventId = u32;
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-	const SECOND_IN_YEAR: u32 = 31622400;
 	const MAX_YEARS: u32 = 100;
 
 	#[pallet::storage]
@@ -39,10 +75,283 @@ pub mod pallet {
 		Club<T::AccountId, <pallet_timestamp::Pallet<T> as frame_support::traits::Time>::Moment>,
 	>;
 
+	/// The account receiving the revenue of the clubs owned by an account, if not the owner
+	/// itself. Only the first destination is used. Each destination holds a consumer reference.
 	#[pallet::storage]
 	pub type Payouts<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
 
+	/// Members whose status is temporarily blocked by the club owner. The paid time is kept.
+	#[pallet::storage]
+	pub type Suspended<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClubId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Accounts banned from a club, with an optional hash of the reason.
+	#[pallet::storage]
+	pub type Bans<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClubId,
+		Blake2_128Concat,
+		T::AccountId,
+		Option<T::Hash>,
+	>;
+
+	/// How accounts that are not yet members may be admitted into a club.
+	#[pallet::storage]
+	pub type AdmissionPolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClubId, AdmissionPolicy, ValueQuery>;
+
+	/// Group membership tier offered by a club.
+	#[pallet::storage]
+	pub type GroupTiers<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, GroupTier>;
+
+	/// Dependents attached to a primary member's group membership.
+	#[pallet::storage]
+	pub type Groups<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClubId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDependents>,
+		ValueQuery,
+	>;
+
+	/// The primary member each dependent belongs to.
+	#[pallet::storage]
+	pub type Dependents<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClubId, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// Length of the free trial membership offered by a club.
+	#[pallet::storage]
+	pub type TrialLengths<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, T::Moment>;
+
+	/// Trials taken per club. An account can only start one trial in each club.
+	#[pallet::storage]
+	pub type Trials<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClubId, Blake2_128Concat, T::AccountId, TrialStatus>;
+
+	/// Price of a lifetime membership, for clubs that offer one.
+	#[pallet::storage]
+	pub type LifetimePrices<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, u32>;
+
+	/// Members that paid for a lifetime membership and never expire.
+	#[pallet::storage]
+	pub type LifetimeMembers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClubId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TrialStatus {
+		/// The trial was started and not paid for yet.
+		Started,
+		/// The member paid for the membership after the trial.
+		Converted,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct GroupTier {
+		/// Annual price paid by the primary member for the whole group.
+		pub annual_expenses: u32,
+		/// Maximum number of dependents that can be attached to a primary member, at most
+		/// `MaxDependents`.
+		pub max_dependents: u32,
+	}
+
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum AdmissionPolicy {
+		/// Only the club owner can admit new members.
+		#[default]
+		OwnerOnly,
+		/// Anyone can become a member by paying for the membership.
+		Open,
+	}
+
+	/// Identifier of the next event to be created.
+	#[pallet::storage]
+	pub type NextEventId<T: Config> = StorageValue<_, EventId, ValueQuery>;
+
+	/// Events hosted by clubs.
+	#[pallet::storage]
+	pub type ClubEvents<T: Config> = StorageMap<_, Blake2_128Concat, EventId, ClubEvent<T::Moment>>;
+
+	/// Tickets sold for each event, with the price paid.
+	#[pallet::storage]
+	pub type Tickets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, EventId, Blake2_128Concat, T::AccountId, Ticket>;
+
+	/// Refunds of cancelled events that could not be paid out, to be claimed by the ticket
+	/// holders. A refund below the existential deposit can only be claimed by a holder whose
+	/// account exists.
+	#[pallet::storage]
+	pub type PendingRefunds<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, EventId, Blake2_128Concat, T::AccountId, u32>;
+
+	/// The part of each club's treasury that is owed to others: the revenue of its scheduled
+	/// events and the pending refunds of its cancelled ones. It is never used to sponsor fees.
+	#[pallet::storage]
+	pub type Escrow<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, u32, ValueQuery>;
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClubEvent<M> {
+		pub club_id: ClubId,
+		pub capacity: u32,
+		pub sold: u32,
+		pub member_price: u32,
+		pub guest_price: u32,
+		pub start: M,
+		/// Ticket revenue held in the club's treasury until the event is settled, or the refunds
+		/// still to be claimed once it is cancelled.
+		pub revenue: u32,
+		pub status: ClubEventStatus,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ClubEventStatus {
+		/// Tickets can be bought and checked in.
+		Scheduled,
+		/// The event was cancelled and its tickets refunded, possibly with `PendingRefunds`.
+		Cancelled,
+		/// The ticket revenue was paid out to the club.
+		Settled,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Ticket {
+		pub price: u32,
+		pub checked_in: bool,
+	}
+
+	/// Moment each member joined the club, used to compute tenure.
+	#[pallet::storage]
+	pub type JoinedAt<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClubId, Blake2_128Concat, T::AccountId, T::Moment>;
+
+	/// Loyalty points balance of each member.
+	#[pallet::storage]
+	pub type Points<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClubId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Most recent loyalty points changes of each member. The oldest entries are dropped once
+	/// `MaxLedgerEntries` is reached.
+	#[pallet::storage]
+	pub type PointsLedger<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClubId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<PointsEntry<T::Moment>, T::MaxLedgerEntries>,
+		ValueQuery,
+	>;
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PointsEntry<M> {
+		pub reason: PointsReason,
+		/// Points earned, or spent when negative.
+		pub change: i64,
+		pub at: M,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PointsReason {
+		/// Renewing the membership, including the tenure bonus.
+		Renewal,
+		/// Checking in at a club event.
+		Attendance,
+		/// Bringing a new member into the club.
+		Referral,
+		/// Granted or deducted by the club owner, with an owner-defined code.
+		Owner(u8),
+		/// Spent on a membership discount.
+		Discount,
+	}
+
+	/// How new members join each club.
+	#[pallet::storage]
+	pub type JoinModes<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClubId, JoinMode<T::Moment>, ValueQuery>;
+
+	/// Stakes reserved by members of clubs in stake mode.
+	#[pallet::storage]
+	pub type Stakes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClubId,
+		Blake2_128Concat,
+		T::AccountId,
+		StakeInfo<T::Moment>,
+	>;
+
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum JoinMode<M> {
+		/// New members pay a membership fee.
+		#[default]
+		Fee,
+		/// New members reserve `amount` on their account for as long as they stay members, and
+		/// must wait `cooldown` after requesting to unlock it.
+		Stake { amount: u32, cooldown: M },
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct StakeInfo<M> {
+		pub amount: u32,
+		/// The cooldown of the club when the stake was made, which is what the member agreed
+		/// to.
+		pub cooldown: M,
+		/// When the stake can be withdrawn, once the member has asked to unlock it.
+		pub unlock_at: Option<M>,
+	}
+
+	/// How much of their members' transaction fees each club pays, see [`SponsorClubFees`].
+	#[pallet::storage]
+	pub type FeePolicies<T: Config> = StorageMap<_, Blake2_128Concat, ClubId, FeePolicy>;
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FeePolicy {
+		/// The part of the fee paid from the club's treasury.
+		pub discount: Percent,
+		pub scope: FeeScope,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FeeScope {
+		/// Only calls into this pallet are sponsored.
+		ClubCalls,
+		/// Any call signed by an active member is sponsored.
+		AllCalls,
+	}
+
+	/// A club created at genesis.
+	#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct GenesisClub<AccountId> {
+		pub id: ClubId,
+		pub owner: AccountId,
+		/// The annual membership price.
+		pub price: u32,
+		/// The initial members and the timestamps at which their memberships end.
+		pub members: Vec<(AccountId, u64)>,
+		/// The members whose membership is for life. Their memberships may have ended.
+		#[cfg_attr(feature = "std", serde(default))]
+		pub lifetime: Vec<AccountId>,
+		/// The members whose membership is suspended.
+		#[cfg_attr(feature = "std", serde(default))]
+		pub suspended: Vec<AccountId>,
+	}
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct Club<A, M> {
 		pub owner: A,
@@ -50,7 +359,11 @@ pub mod pallet {
 		pub members: BTreeMap<A, M>,
 	}
 
+	/// The current version of the storage, see [`crate::migrations`].
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -58,11 +371,55 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config + timestamp::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ TryInto<Event<Self>>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
-		/// The currency used for deposits.
-		type Currency: Currency<Self::AccountId>;
+		/// The currency used for deposits and for the stakes of clubs in stake mode, which are
+		/// reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The club pallet's id, used for deriving the treasury account of each club.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The maximum number of tickets that can be sold for a single event.
+		#[pallet::constant]
+		type MaxEventCapacity: Get<u32>;
+		/// The maximum number of members of a club, dependents not included.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+		/// The maximum number of dependents of a group membership.
+		#[pallet::constant]
+		type MaxDependents: Get<u32>;
+		/// Points earned for each membership renewal.
+		#[pallet::constant]
+		type PointsPerRenewal: Get<u32>;
+		/// Extra points earned on renewal for each full year since the member joined.
+		#[pallet::constant]
+		type PointsPerTenureYear: Get<u32>;
+		/// Points earned for checking in at a club event.
+		#[pallet::constant]
+		type PointsPerAttendance: Get<u32>;
+		/// Points earned for bringing a new member into the club.
+		#[pallet::constant]
+		type PointsPerReferral: Get<u32>;
+		/// Points needed for a discount of one unit of the membership price.
+		#[pallet::constant]
+		type PointsPerDiscountUnit: Get<u32>;
+		/// The maximum number of entries kept in each member's points ledger.
+		#[pallet::constant]
+		type MaxLedgerEntries: Get<u32>;
+		/// Handler notified when accounts become or stop being active members of a club. Its
+		/// maximum weight is charged by every call that may change memberships.
+		type MembershipChanged: OnMembershipChanged<Self::AccountId>;
+		/// The priority given to valid membership renewals in the transaction pool.
+		#[pallet::constant]
+		type RenewalPriority: Get<TransactionPriority>;
+		/// The length of a paid year of membership, in the unit of the timestamp pallet's
+		/// `Moment`: 31_622_400_000 for 366 days of milliseconds. The paid time of chains with
+		/// storage version 0 is converted to it by [`crate::migrations::v1::MigrateToV1`].
+		#[pallet::constant]
+		type MomentsPerYear: Get<Self::Moment>;
 	}
 
 	// Pallets use events to inform users when important changes are made.
@@ -70,11 +427,165 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ClubCreated { club_id: ClubId },
-		MemberAdded { member: T::AccountId, club_id: ClubId },
-		NewOwner { new_owner: T::AccountId, club_id: ClubId },
-		MembershipExpencesPayed { member: T::AccountId, club_id: ClubId },
-		AnnualExpencesSet { club_id: ClubId },
+		ClubCreated {
+			club_id: ClubId,
+		},
+		MemberAdded {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		NewOwner {
+			new_owner: T::AccountId,
+			club_id: ClubId,
+		},
+		MembershipExpencesPayed {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		AnnualExpencesSet {
+			club_id: ClubId,
+		},
+		MemberSuspended {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		MemberUnsuspended {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		AccountBanned {
+			account: T::AccountId,
+			club_id: ClubId,
+			reason: Option<T::Hash>,
+		},
+		AccountUnbanned {
+			account: T::AccountId,
+			club_id: ClubId,
+		},
+		AdmissionPolicySet {
+			club_id: ClubId,
+			policy: AdmissionPolicy,
+		},
+		MembershipGifted {
+			payer: T::AccountId,
+			beneficiary: T::AccountId,
+			club_id: ClubId,
+			amount: u32,
+			new_expiry: T::Moment,
+		},
+		GroupTierSet {
+			club_id: ClubId,
+		},
+		GroupMembershipPayed {
+			primary: T::AccountId,
+			club_id: ClubId,
+		},
+		DependentAdded {
+			primary: T::AccountId,
+			dependent: T::AccountId,
+			club_id: ClubId,
+		},
+		DependentRemoved {
+			primary: T::AccountId,
+			dependent: T::AccountId,
+			club_id: ClubId,
+		},
+		GroupDissolved {
+			primary: T::AccountId,
+			club_id: ClubId,
+		},
+		TrialLengthSet {
+			club_id: ClubId,
+		},
+		TrialStarted {
+			member: T::AccountId,
+			club_id: ClubId,
+			expiry: T::Moment,
+		},
+		TrialConverted {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		LifetimePriceSet {
+			club_id: ClubId,
+			price: Option<u32>,
+		},
+		LifetimeMembershipPayed {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		EventCreated {
+			event_id: EventId,
+			club_id: ClubId,
+		},
+		TicketBought {
+			event_id: EventId,
+			buyer: T::AccountId,
+			price: u32,
+		},
+		TicketCheckedIn {
+			event_id: EventId,
+			holder: T::AccountId,
+		},
+		EventCancelled {
+			event_id: EventId,
+			refunded: u32,
+		},
+		EventSettled {
+			event_id: EventId,
+			revenue: u32,
+		},
+		RefundFailed {
+			event_id: EventId,
+			holder: T::AccountId,
+			amount: u32,
+		},
+		RefundClaimed {
+			event_id: EventId,
+			holder: T::AccountId,
+			amount: u32,
+		},
+		PointsAwarded {
+			club_id: ClubId,
+			member: T::AccountId,
+			amount: u32,
+			reason: PointsReason,
+		},
+		PointsDeducted {
+			club_id: ClubId,
+			member: T::AccountId,
+			amount: u32,
+			reason: PointsReason,
+		},
+		JoinModeSet {
+			club_id: ClubId,
+		},
+		Staked {
+			member: T::AccountId,
+			club_id: ClubId,
+			amount: u32,
+		},
+		UnlockRequested {
+			member: T::AccountId,
+			club_id: ClubId,
+			unlock_at: T::Moment,
+		},
+		StakeWithdrawn {
+			member: T::AccountId,
+			club_id: ClubId,
+		},
+		FeePolicySet {
+			club_id: ClubId,
+		},
+		FeesSponsored {
+			club_id: ClubId,
+			who: T::AccountId,
+			amount: u32,
+		},
+		PayoutDestinationSet {
+			account: T::AccountId,
+			destination: Option<T::AccountId>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -88,128 +599,1609 @@ pub mod pallet {
 		NotMember,
 		TooManyTokens,
 		ClubDoesNotExist,
+		/// The member is suspended and cannot act as a member until unsuspended.
+		MemberSuspended,
+		/// The member is not suspended.
+		NotSuspended,
+		/// The account is banned from the club.
+		AccountBanned,
+		/// The account is not banned from the club.
+		NotBanned,
+		/// The club's admission policy does not allow the account to be admitted this way.
+		AdmissionDenied,
+		/// The club does not offer group memberships.
+		NoGroupTier,
+		/// The caller has not paid for a group membership.
+		NotGroupPrimary,
+		/// The paid membership has expired.
+		MembershipExpired,
+		/// The group already has the maximum number of dependents.
+		TooManyDependents,
+		/// The account is already a member or a dependent in the club.
+		AlreadyMember,
+		/// The account is not a dependent of the caller.
+		NotDependent,
+		/// The club does not offer trial memberships.
+		NoTrial,
+		/// The account has already had a trial in this club.
+		TrialAlreadyUsed,
+		/// The club does not offer lifetime memberships.
+		NoLifetimeMembership,
+		/// The member already has a lifetime membership.
+		LifetimeMember,
+		/// The event does not exist.
+		EventDoesNotExist,
+		/// The event has already started.
+		EventStarted,
+		/// The event has not started yet.
+		EventNotStarted,
+		/// The event was cancelled or settled.
+		EventClosed,
+		/// The event capacity is above `MaxEventCapacity`.
+		CapacityTooLarge,
+		/// All tickets for the event are sold.
+		SoldOut,
+		/// The account already holds a ticket for the event.
+		AlreadyHasTicket,
+		/// The account does not hold a ticket for the event.
+		NoTicket,
+		/// The ticket was already checked in.
+		AlreadyCheckedIn,
+		/// The member does not have enough loyalty points.
+		InsufficientPoints,
+		/// The club does not accept new members by staking.
+		NotStakeMode,
+		/// The account does not have enough free balance for the stake.
+		InsufficientBalance,
+		/// The account has no stake in the club.
+		NotStaked,
+		/// The stake is already being unlocked.
+		AlreadyUnlocking,
+		/// The stake cooldown has not passed yet.
+		StakeLocked,
+		/// The club has reached `MaxMembers`.
+		TooManyMembers,
+		/// A membership price cannot be zero.
+		ZeroPrice,
+		/// Trials cannot be longer than `MAX_YEARS`.
+		TrialTooLong,
+		/// The account does not exist.
+		AccountDoesNotExist,
+		/// The expense does not pay for a whole year of membership.
+		TooFewTokens,
+		/// The account has no pending refund for the event.
+		NoPendingRefund,
+		/// A group tier cannot allow more than `MaxDependents` dependents.
+		DependentLimitTooHigh,
 	}
 
-	fn ensure_owner<T: Config>(origin: OriginFor<T>, club_id: ClubId) -> DispatchResult {
+	/// Ensure `origin` is signed by the owner of the club, and return the club.
+	fn ensure_owner<T: Config>(
+		origin: OriginFor<T>,
+		club_id: ClubId,
+	) -> Result<Club<T::AccountId, T::Moment>, DispatchError> {
 		let candidate = ensure_signed(origin)?;
 		let club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
 		if club.owner == candidate {
-			Ok(())
+			Ok(club)
 		} else {
 			Err(Error::<T>::NotOwner.into())
 		}
 	}
 
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub clubs: Vec<GenesisClub<T::AccountId>>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { clubs: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			// The timestamp is still zero, so expiries can only be checked against the longest
+			// payable period. The join times of the members are not known and left unset; tenure
+			// counts from their first renewal.
+			if let Err(e) = validate_genesis_clubs(
+				&self.clubs,
+				0,
+				Pallet::<T>::max_period().saturated_into(),
+				T::MaxMembers::get(),
+			) {
+				panic!("invalid genesis clubs: {:?}", e);
+			}
+
+			for club in &self.clubs {
+				if frame_system::Pallet::<T>::inc_consumers_without_limit(&club.owner).is_err() {
+					panic!("invalid genesis clubs: the owner of club {} does not exist", club.id);
+				}
+				let members = club
+					.members
+					.iter()
+					.map(|(member, expiry)| (member.clone(), (*expiry).saturated_into()))
+					.collect();
+				PalletStorage::<T>::insert(
+					club.id,
+					Club { owner: club.owner.clone(), annual_expenses: club.price, members },
+				);
+				for member in &club.lifetime {
+					LifetimeMembers::<T>::insert(club.id, member, ());
+				}
+				for member in &club.suspended {
+					Suspended::<T>::insert(club.id, member, ());
+				}
+			}
+		}
+	}
+
+	/// Check `clubs` against the constraints of the pallet: club ids and the members of each
+	/// club are unique, owners are not members of their own club, prices are not zero, clubs
+	/// have at most `max_members` members, lifetime and suspended accounts are members, and
+	/// memberships end after `now`, unless they are for life, but not after `max_end`.
+	pub fn validate_genesis_clubs<AccountId: Ord>(
+		clubs: &[GenesisClub<AccountId>],
+		now: u64,
+		max_end: u64,
+		max_members: u32,
+	) -> Result<(), GenesisClubError> {
+		let mut ids = BTreeSet::new();
+		for club in clubs {
+			ensure!(ids.insert(club.id), GenesisClubError::DuplicateClub(club.id));
+			ensure!(club.price > 0, GenesisClubError::ZeroPrice(club.id));
+			ensure!(
+				club.members.len() <= max_members as usize,
+				GenesisClubError::TooManyMembers(club.id)
+			);
+			let mut members = BTreeSet::new();
+			for (member, expiry) in &club.members {
+				ensure!(members.insert(member), GenesisClubError::DuplicateMember(club.id));
+				ensure!(*member != club.owner, GenesisClubError::OwnerIsMember(club.id));
+				ensure!(
+					*expiry > now || club.lifetime.contains(member),
+					GenesisClubError::Expired(club.id)
+				);
+				ensure!(*expiry <= max_end, GenesisClubError::TooFarAhead(club.id));
+			}
+			for account in club.lifetime.iter().chain(&club.suspended) {
+				ensure!(members.contains(account), GenesisClubError::NotMember(club.id));
+			}
+		}
+		Ok(())
+	}
+
+	/// Why [`validate_genesis_clubs`] rejected a club.
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+	pub enum GenesisClubError {
+		/// Two clubs share the id.
+		DuplicateClub(ClubId),
+		/// The club has a price of zero.
+		ZeroPrice(ClubId),
+		/// The club has more than `MaxMembers` members.
+		TooManyMembers(ClubId),
+		/// An account is listed twice as a member of the club.
+		DuplicateMember(ClubId),
+		/// A membership of the club ends before genesis.
+		Expired(ClubId),
+		/// A membership of the club ends later than can be paid for in advance.
+		TooFarAhead(ClubId),
+		/// The owner of the club is listed as a member of it.
+		OwnerIsMember(ClubId),
+		/// A lifetime or suspended account of the club is not one of its members.
+		NotMember(ClubId),
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Keep the offchain membership index up to date, see [`crate::offchain`], and send the
+		/// expiry notifications of [`crate::webhook`].
+		fn offchain_worker(block_number: T::BlockNumber) {
+			Self::update_index(block_number);
+			Self::send_expiry_notifications();
+		}
+
+		fn integrity_test() {
+			assert!(!T::MomentsPerYear::get().is_zero(), "a year must not be empty");
+		}
+
+		/// Check the invariants of [`crate::invariants`].
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_club())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_club(T::MaxMembers::get()))]
 		pub fn create_club(
 			origin: OriginFor<T>,
 			owner: T::AccountId,
 			club_id: ClubId,
 			annual_expenses: u32,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
+			ensure!(annual_expenses > 0, Error::<T>::ZeroPrice);
+			// The club keeps its owner from being reaped.
+			frame_system::Pallet::<T>::inc_consumers_without_limit(&owner)
+				.map_err(|_| Error::<T>::AccountDoesNotExist)?;
+			let replaced = PalletStorage::<T>::get(club_id);
+			if let Some(replaced) = &replaced {
+				frame_system::Pallet::<T>::dec_consumers(&replaced.owner);
+			}
+			let members = replaced.map_or(0, |club| club.members.len() as u32);
 
 			PalletStorage::<T>::insert(
 				club_id,
 				Club { owner, members: Default::default(), annual_expenses },
 			);
 			Self::deposit_event(Event::ClubCreated { club_id });
-			Ok(())
+			Ok(Some(<T as pallet::Config>::WeightInfo::create_club(members)).into())
 		}
 
 		// Add a new member to your club
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_member())]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::add_member(T::MaxMembers::get())
+				.saturating_add(T::MembershipChanged::max_weight())
+		)]
 		pub fn add_member(
 			origin: OriginFor<T>,
 			club_id: ClubId,
 			member: T::AccountId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin.clone())?;
 			ensure_owner::<T>(origin, club_id)?;
-			let mut club = PalletStorage::<T>::get(club_id).unwrap(); // need to fix the wrapping
+			let before = Self::membership_snapshot(club_id, &member);
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			ensure!(!Bans::<T>::contains_key(club_id, &member), Error::<T>::AccountBanned);
+			ensure!(
+				!club.members.contains_key(&member) &&
+					!Dependents::<T>::contains_key(club_id, &member),
+				Error::<T>::AlreadyMember
+			);
+			Self::ensure_room(&club)?;
+			let members = club.members.len() as u32;
 
 			T::Currency::transfer(&member, &owner, 1u32.into(), AllowDeath)?;
 
 			let now = <timestamp::Pallet<T>>::get();
 			club.members.insert(member.clone(), now);
+			Self::note_joined(club_id, &member);
 
 			PalletStorage::<T>::insert(club_id, club);
 			Self::deposit_event(Event::MemberAdded { member, club_id });
-			Ok(())
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::add_member(members).saturating_add(notified),
+			)
+			.into())
 		}
 
 		// Change owner of the club
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_ownership())]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::transfer_ownership(T::MaxMembers::get())
+		)]
 		pub fn transfer_ownership(
 			origin: OriginFor<T>,
 			new_owner: T::AccountId,
 			club_id: ClubId,
-		) -> DispatchResult {
-			ensure_owner::<T>(origin, club_id)?;
-
-			let mut club = PalletStorage::<T>::get(club_id).unwrap();
+		) -> DispatchResultWithPostInfo {
+			let mut club = ensure_owner::<T>(origin, club_id)?;
+			let members = club.members.len() as u32;
+			frame_system::Pallet::<T>::inc_consumers_without_limit(&new_owner)
+				.map_err(|_| Error::<T>::AccountDoesNotExist)?;
+			frame_system::Pallet::<T>::dec_consumers(&club.owner);
 			club.owner = new_owner.clone();
 
 			PalletStorage::<T>::insert(club_id, club);
 			Self::deposit_event(Event::NewOwner { new_owner, club_id });
-			Ok(())
+			Ok(Some(<T as pallet::Config>::WeightInfo::transfer_ownership(members)).into())
 		}
 
 		/// Set the annual expense for club membership.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_annual_expense())]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::set_annual_expense(T::MaxMembers::get())
+		)]
 		pub fn set_annual_expense(
 			origin: OriginFor<T>,
 			club_id: ClubId,
 			expense: u32,
-		) -> DispatchResult {
-			ensure_owner::<T>(origin, club_id)?;
+		) -> DispatchResultWithPostInfo {
+			let mut club = ensure_owner::<T>(origin, club_id)?;
+			ensure!(expense > 0, Error::<T>::ZeroPrice);
 
-			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let members = club.members.len() as u32;
 			club.annual_expenses = expense;
 
 			PalletStorage::<T>::insert(club_id, club);
 			Self::deposit_event(Event::AnnualExpencesSet { club_id });
-			Ok(())
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_annual_expense(members)).into())
 		}
 
 		// Pay for being a member
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_membership_expense())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_membership_expense(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
 		pub fn pay_membership_expense(
 			origin: OriginFor<T>,
 			club_id: ClubId,
 			expense: u32,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (members, dependents, notified) =
+				Self::do_renew_membership(caller, club_id, expense, 0)?;
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::pay_membership_expense(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Suspend a member. The paid time is kept, but the account is not treated as a member
+		/// until it is unsuspended.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::suspend_member(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn suspend_member(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			member: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let club = ensure_owner::<T>(origin, club_id)?;
+			let before = Self::membership_snapshot(club_id, &member);
+
+			ensure!(club.members.contains_key(&member), Error::<T>::NotMember);
+			ensure!(!Suspended::<T>::contains_key(club_id, &member), Error::<T>::MemberSuspended);
+
+			let members = club.members.len() as u32;
+			let dependents = Self::dependent_count(club_id, &member);
+			Suspended::<T>::insert(club_id, &member, ());
+			Self::deposit_event(Event::MemberSuspended { member, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::suspend_member(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Lift the suspension of a member.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unsuspend_member(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn unsuspend_member(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			member: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			let before = Self::membership_snapshot(club_id, &member);
+			ensure!(Suspended::<T>::contains_key(club_id, &member), Error::<T>::NotSuspended);
+
+			let dependents = Self::dependent_count(club_id, &member);
+			Suspended::<T>::remove(club_id, &member);
+			Self::deposit_event(Event::MemberUnsuspended { member, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::unsuspend_member(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Ban an account from the club. A current member is removed and the account cannot
+		/// rejoin until it is unbanned.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::ban_account(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn ban_account(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			account: T::AccountId,
+			reason: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			ensure_owner::<T>(origin, club_id)?;
+			let before = Self::membership_snapshot(club_id, &account);
+			ensure!(!Bans::<T>::contains_key(club_id, &account), Error::<T>::AccountBanned);
+
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let members = club.members.len() as u32;
+			// Banning a dependent shrinks the group of its primary member instead.
+			let dependents = match Dependents::<T>::get(club_id, &account) {
+				Some(primary) => Self::dependent_count(club_id, &primary),
+				None => Self::dependent_count(club_id, &account),
+			};
+			if club.members.remove(&account).is_some() {
+				PalletStorage::<T>::insert(club_id, club);
+			}
+			Suspended::<T>::remove(club_id, &account);
+			LifetimeMembers::<T>::remove(club_id, &account);
+			if let Some(stake) = Stakes::<T>::take(club_id, &account) {
+				T::Currency::unreserve(&account, stake.amount.into());
+			}
+			JoinedAt::<T>::remove(club_id, &account);
+			for dependent in Groups::<T>::take(club_id, &account) {
+				Dependents::<T>::remove(club_id, &dependent);
+			}
+			if let Some(primary) = Dependents::<T>::take(club_id, &account) {
+				Groups::<T>::mutate(club_id, &primary, |group| group.retain(|d| d != &account));
+			}
+
+			Bans::<T>::insert(club_id, &account, reason);
+			Self::deposit_event(Event::AccountBanned { account, club_id, reason });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::ban_account(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Lift a ban, allowing the account to be added to the club again.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unban_account(T::MaxMembers::get()))]
+		pub fn unban_account(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(Bans::<T>::contains_key(club_id, &account), Error::<T>::NotBanned);
+
+			Bans::<T>::remove(club_id, &account);
+			Self::deposit_event(Event::AccountUnbanned { account, club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::unban_account(members)).into())
+		}
+
+		/// Set how new members may be admitted into the club.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::set_admission_policy(T::MaxMembers::get())
+		)]
+		pub fn set_admission_policy(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			policy: AdmissionPolicy,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+
+			AdmissionPolicies::<T>::insert(club_id, policy);
+			Self::deposit_event(Event::AdmissionPolicySet { club_id, policy });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_admission_policy(members)).into())
+		}
+
+		/// Pay for someone else's membership. Renewals are open to anyone, while gifting a new
+		/// membership is subject to the club's admission policy. A gift to the primary member of
+		/// a group renews the group at the group price and keeps its dependents.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::gift_membership(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn gift_membership(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			beneficiary: T::AccountId,
+			amount: u32,
+		) -> DispatchResultWithPostInfo {
+			let payer = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &beneficiary);
+
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			ensure!(!Bans::<T>::contains_key(club_id, &beneficiary), Error::<T>::AccountBanned);
+			ensure!(
+				!Dependents::<T>::contains_key(club_id, &beneficiary),
+				Error::<T>::AlreadyMember
+			);
+			ensure!(
+				!Suspended::<T>::contains_key(club_id, &beneficiary),
+				Error::<T>::MemberSuspended
+			);
+			ensure!(
+				!LifetimeMembers::<T>::contains_key(club_id, &beneficiary),
+				Error::<T>::LifetimeMember
+			);
+
+			let members = club.members.len() as u32;
+			let dependents = Self::dependent_count(club_id, &beneficiary);
+			let is_new_member = !club.members.contains_key(&beneficiary);
+			if is_new_member {
+				let admitted = match AdmissionPolicies::<T>::get(club_id) {
+					AdmissionPolicy::OwnerOnly => payer == club.owner,
+					AdmissionPolicy::Open => true,
+				};
+				ensure!(admitted, Error::<T>::AdmissionDenied);
+				Self::ensure_room(&club)?;
+				club.members.insert(beneficiary.clone(), <timestamp::Pallet<T>>::get());
+				Self::note_joined(club_id, &beneficiary);
+				if Self::is_active_member(club_id, &payer) {
+					Self::award_points(
+						club_id,
+						&payer,
+						T::PointsPerReferral::get(),
+						PointsReason::Referral,
+					);
+				}
+			}
+
+			let price = if Groups::<T>::contains_key(club_id, &beneficiary) {
+				GroupTiers::<T>::get(club_id).ok_or(Error::<T>::NoGroupTier)?.annual_expenses
+			} else {
+				club.annual_expenses
+			};
+			if !is_new_member {
+				Self::award_renewal_points(club_id, &beneficiary);
+			}
+			let new_expiry =
+				Self::do_pay_membership(&mut club, &payer, &beneficiary, price, 0, amount)?;
+
+			PalletStorage::<T>::insert(club_id, club);
+			if is_new_member {
+				Self::deposit_event(Event::MemberAdded { member: beneficiary.clone(), club_id });
+			}
+			Self::deposit_event(Event::MembershipGifted {
+				payer,
+				beneficiary: beneficiary.clone(),
+				club_id,
+				amount,
+				new_expiry,
+			});
+			Self::convert_trial(club_id, beneficiary);
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::gift_membership(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Offer group memberships at the given tier, or stop offering them with `None`.
+		/// Existing groups keep their dependents.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_group_tier(T::MaxMembers::get()))]
+		pub fn set_group_tier(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			tier: Option<GroupTier>,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(
+				tier.as_ref().map_or(true, |tier| tier.annual_expenses > 0),
+				Error::<T>::ZeroPrice
+			);
+			ensure!(
+				tier.as_ref()
+					.map_or(true, |tier| tier.max_dependents <= T::MaxDependents::get()),
+				Error::<T>::DependentLimitTooHigh
+			);
+
+			GroupTiers::<T>::set(club_id, tier);
+			Self::deposit_event(Event::GroupTierSet { club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_group_tier(members)).into())
+		}
+
+		/// Pay for a group membership at the club's group price. The caller becomes the primary
+		/// member of the group and can attach dependents to it.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_group_membership(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn pay_group_membership(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			expense: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &caller);
+
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let tier = GroupTiers::<T>::get(club_id).ok_or(Error::<T>::NoGroupTier)?;
+			ensure!(club.members.contains_key(&caller), Error::<T>::NotMember);
+			ensure!(!Suspended::<T>::contains_key(club_id, &caller), Error::<T>::MemberSuspended);
+			ensure!(
+				!LifetimeMembers::<T>::contains_key(club_id, &caller),
+				Error::<T>::LifetimeMember
+			);
+
+			Self::do_pay_membership(&mut club, &caller, &caller, tier.annual_expenses, 0, expense)?;
+			let members = club.members.len() as u32;
+			let dependents = Self::dependent_count(club_id, &caller);
+
+			PalletStorage::<T>::insert(club_id, club);
+			Self::award_renewal_points(club_id, &caller);
+			if !Groups::<T>::contains_key(club_id, &caller) {
+				Groups::<T>::insert(club_id, &caller, BoundedVec::default());
+			}
+			Self::deposit_event(Event::GroupMembershipPayed { primary: caller, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::pay_group_membership(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Attach a dependent to the caller's group membership. The dependent shares the
+		/// primary member's expiry, so the group membership must not have expired.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_dependent(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn add_dependent(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			dependent: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let primary = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &dependent);
+
+			let club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let tier = GroupTiers::<T>::get(club_id).ok_or(Error::<T>::NoGroupTier)?;
+			ensure!(Groups::<T>::contains_key(club_id, &primary), Error::<T>::NotGroupPrimary);
+			ensure!(!Suspended::<T>::contains_key(club_id, &primary), Error::<T>::MemberSuspended);
+			let now = <timestamp::Pallet<T>>::get();
+			ensure!(
+				Self::membership_expiry(club_id, &primary).map_or(false, |expiry| expiry > now),
+				Error::<T>::MembershipExpired
+			);
+			ensure!(!Bans::<T>::contains_key(club_id, &dependent), Error::<T>::AccountBanned);
+			ensure!(
+				!club.members.contains_key(&dependent) &&
+					!Dependents::<T>::contains_key(club_id, &dependent),
+				Error::<T>::AlreadyMember
+			);
+
+			let members = club.members.len() as u32;
+			let dependents = Groups::<T>::try_mutate(club_id, &primary, |group| {
+				let dependents = group.len() as u32;
+				ensure!(dependents < tier.max_dependents, Error::<T>::TooManyDependents);
+				group.try_push(dependent.clone()).map_err(|_| Error::<T>::TooManyDependents)?;
+				Ok::<_, DispatchError>(dependents)
+			})?;
+			Dependents::<T>::insert(club_id, &dependent, &primary);
+			Self::deposit_event(Event::DependentAdded { primary, dependent, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::add_dependent(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Detach a dependent from the caller's group membership.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_dependent(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn remove_dependent(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			dependent: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let primary = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &dependent);
+			ensure!(
+				Dependents::<T>::get(club_id, &dependent).as_ref() == Some(&primary),
+				Error::<T>::NotDependent
+			);
+
+			let members = Self::member_count(club_id);
+			let dependents = Self::dependent_count(club_id, &primary);
+			Groups::<T>::mutate(club_id, &primary, |group| group.retain(|d| d != &dependent));
+			Dependents::<T>::remove(club_id, &dependent);
+			Self::deposit_event(Event::DependentRemoved { primary, dependent, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::remove_dependent(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Offer free trial memberships of the given length, or stop offering them with `None`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_trial_length(T::MaxMembers::get()))]
+		pub fn set_trial_length(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			length: Option<T::Moment>,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(
+				length.map_or(true, |length| length <= Self::max_period()),
+				Error::<T>::TrialTooLong
+			);
+
+			TrialLengths::<T>::set(club_id, length);
+			Self::deposit_event(Event::TrialLengthSet { club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_trial_length(members)).into())
+		}
+
+		/// Become a member for the club's trial length without paying. Each account can start
+		/// a trial only once per club.
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::start_trial(T::MaxMembers::get())
+				.saturating_add(T::MembershipChanged::max_weight())
+		)]
+		pub fn start_trial(origin: OriginFor<T>, club_id: ClubId) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &caller);
+
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let length = TrialLengths::<T>::get(club_id).ok_or(Error::<T>::NoTrial)?;
+			ensure!(!Trials::<T>::contains_key(club_id, &caller), Error::<T>::TrialAlreadyUsed);
+			ensure!(!Bans::<T>::contains_key(club_id, &caller), Error::<T>::AccountBanned);
+			ensure!(
+				!club.members.contains_key(&caller) &&
+					!Dependents::<T>::contains_key(club_id, &caller),
+				Error::<T>::AlreadyMember
+			);
+
+			Self::ensure_room(&club)?;
+			let members = club.members.len() as u32;
+
+			let expiry = <timestamp::Pallet<T>>::get().saturating_add(length);
+			club.members.insert(caller.clone(), expiry);
+
+			PalletStorage::<T>::insert(club_id, club);
+			Self::note_joined(club_id, &caller);
+			Trials::<T>::insert(club_id, &caller, TrialStatus::Started);
+			Self::deposit_event(Event::MemberAdded { member: caller.clone(), club_id });
+			Self::deposit_event(Event::TrialStarted { member: caller, club_id, expiry });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::start_trial(members).saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Offer lifetime memberships at the given price, or withdraw the offer with `None`.
+		/// Withdrawing the offer does not affect existing lifetime members.
+		#[pallet::call_index(19)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::set_lifetime_price(T::MaxMembers::get())
+		)]
+		pub fn set_lifetime_price(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			price: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(price != Some(0), Error::<T>::ZeroPrice);
+
+			LifetimePrices::<T>::set(club_id, price);
+			Self::deposit_event(Event::LifetimePriceSet { club_id, price });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_lifetime_price(members)).into())
+		}
+
+		/// Pay the club's lifetime price. The membership never expires afterwards.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_lifetime_membership(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn pay_lifetime_membership(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &caller);
+
+			let club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let price =
+				LifetimePrices::<T>::get(club_id).ok_or(Error::<T>::NoLifetimeMembership)?;
+			ensure!(club.members.contains_key(&caller), Error::<T>::NotMember);
+			ensure!(!Suspended::<T>::contains_key(club_id, &caller), Error::<T>::MemberSuspended);
+			ensure!(
+				!LifetimeMembers::<T>::contains_key(club_id, &caller),
+				Error::<T>::LifetimeMember
+			);
+
+			T::Currency::transfer(&caller, &club.owner, price.into(), AllowDeath)?;
+
+			let members = club.members.len() as u32;
+			let dependents = Self::dependent_count(club_id, &caller);
+			LifetimeMembers::<T>::insert(club_id, &caller, ());
+			Self::deposit_event(Event::LifetimeMembershipPayed { member: caller.clone(), club_id });
+			Self::convert_trial(club_id, caller);
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::pay_lifetime_membership(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Create an event for the club. Members whose membership is active at `start` pay
+		/// `member_price`, everyone else pays `guest_price`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_event(T::MaxMembers::get()))]
+		pub fn create_event(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			capacity: u32,
+			member_price: u32,
+			guest_price: u32,
+			start: T::Moment,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(capacity <= T::MaxEventCapacity::get(), Error::<T>::CapacityTooLarge);
+			ensure!(start > <timestamp::Pallet<T>>::get(), Error::<T>::EventStarted);
+
+			let event_id = NextEventId::<T>::get();
+			let next_event_id = event_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+
+			ClubEvents::<T>::insert(
+				event_id,
+				ClubEvent {
+					club_id,
+					capacity,
+					sold: 0,
+					member_price,
+					guest_price,
+					start,
+					revenue: 0,
+					status: ClubEventStatus::Scheduled,
+				},
+			);
+			NextEventId::<T>::put(next_event_id);
+			Self::deposit_event(Event::EventCreated { event_id, club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::create_event(members)).into())
+		}
+
+		/// Buy a ticket for an event. The price is held in the club's treasury until the event
+		/// is settled, so that it can be refunded if the event is cancelled.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_ticket(T::MaxMembers::get()))]
+		pub fn buy_ticket(origin: OriginFor<T>, event_id: EventId) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+
+			let mut event = ClubEvents::<T>::get(event_id).ok_or(Error::<T>::EventDoesNotExist)?;
+			ensure!(event.status == ClubEventStatus::Scheduled, Error::<T>::EventClosed);
+			ensure!(event.start > <timestamp::Pallet<T>>::get(), Error::<T>::EventStarted);
+			ensure!(event.sold < event.capacity, Error::<T>::SoldOut);
+			ensure!(!Tickets::<T>::contains_key(event_id, &buyer), Error::<T>::AlreadyHasTicket);
+			ensure!(!Bans::<T>::contains_key(event.club_id, &buyer), Error::<T>::AccountBanned);
+
+			let members = Self::member_count(event.club_id);
+			let price = if Self::is_member_at(event.club_id, &buyer, event.start) {
+				event.member_price
+			} else {
+				event.guest_price
+			};
+			T::Currency::transfer(
+				&buyer,
+				&Self::treasury_account(event.club_id),
+				price.into(),
+				AllowDeath,
+			)?;
+
+			event.sold += 1;
+			event.revenue = event.revenue.checked_add(price).ok_or(Error::<T>::StorageOverflow)?;
+			Escrow::<T>::mutate(event.club_id, |escrow| *escrow = escrow.saturating_add(price));
+
+			ClubEvents::<T>::insert(event_id, event);
+			Tickets::<T>::insert(event_id, &buyer, Ticket { price, checked_in: false });
+			Self::deposit_event(Event::TicketBought { event_id, buyer, price });
+			Ok(Some(<T as pallet::Config>::WeightInfo::buy_ticket(members)).into())
+		}
+
+		/// Check in the holder of a ticket at the event.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::check_in(T::MaxMembers::get()))]
+		pub fn check_in(
+			origin: OriginFor<T>,
+			event_id: EventId,
+			holder: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let event = ClubEvents::<T>::get(event_id).ok_or(Error::<T>::EventDoesNotExist)?;
+			let members = ensure_owner::<T>(origin, event.club_id)?.members.len() as u32;
+			ensure!(event.status != ClubEventStatus::Cancelled, Error::<T>::EventClosed);
+
+			Tickets::<T>::try_mutate(event_id, &holder, |ticket| -> DispatchResult {
+				let ticket = ticket.as_mut().ok_or(Error::<T>::NoTicket)?;
+				ensure!(!ticket.checked_in, Error::<T>::AlreadyCheckedIn);
+				ticket.checked_in = true;
+				Ok(())
+			})?;
+			Self::award_points(
+				event.club_id,
+				&holder,
+				T::PointsPerAttendance::get(),
+				PointsReason::Attendance,
+			);
+			Self::deposit_event(Event::TicketCheckedIn { event_id, holder });
+			Ok(Some(<T as pallet::Config>::WeightInfo::check_in(members)).into())
+		}
+
+		/// Cancel an event that has not started yet. Every ticket is refunded from the club's
+		/// treasury. Refunds that fail, for instance because they are below the existential
+		/// deposit of a reaped holder, are kept in `PendingRefunds`. Such a refund can only be
+		/// claimed once the holder's account exists again.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_event(
+			T::MaxEventCapacity::get(),
+			T::MaxMembers::get(),
+		))]
+		pub fn cancel_event(origin: OriginFor<T>, event_id: EventId) -> DispatchResultWithPostInfo {
+			let mut event = ClubEvents::<T>::get(event_id).ok_or(Error::<T>::EventDoesNotExist)?;
+			let members = ensure_owner::<T>(origin, event.club_id)?.members.len() as u32;
+			ensure!(event.status == ClubEventStatus::Scheduled, Error::<T>::EventClosed);
+			ensure!(event.start > <timestamp::Pallet<T>>::get(), Error::<T>::EventStarted);
+
+			let treasury = Self::treasury_account(event.club_id);
+			let mut pending = 0u32;
+			for (holder, ticket) in Tickets::<T>::iter_prefix(event_id) {
+				let amount = ticket.price;
+				if T::Currency::transfer(&treasury, &holder, amount.into(), AllowDeath).is_err() {
+					PendingRefunds::<T>::insert(event_id, &holder, amount);
+					pending = pending.saturating_add(amount);
+					Self::deposit_event(Event::RefundFailed { event_id, holder, amount });
+				}
+			}
+
+			let refunded = event.sold;
+			let paid = event.revenue.saturating_sub(pending);
+			Escrow::<T>::mutate(event.club_id, |escrow| *escrow = escrow.saturating_sub(paid));
+			event.revenue = pending;
+			event.status = ClubEventStatus::Cancelled;
+
+			ClubEvents::<T>::insert(event_id, event);
+			Self::deposit_event(Event::EventCancelled { event_id, refunded });
+			Ok(Some(<T as pallet::Config>::WeightInfo::cancel_event(refunded, members)).into())
+		}
+
+		/// Pay the ticket revenue of an event that has started out to the club. Anyone can
+		/// settle an event.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::settle_event(T::MaxMembers::get()))]
+		pub fn settle_event(origin: OriginFor<T>, event_id: EventId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let mut event = ClubEvents::<T>::get(event_id).ok_or(Error::<T>::EventDoesNotExist)?;
+			ensure!(event.status == ClubEventStatus::Scheduled, Error::<T>::EventClosed);
+			ensure!(event.start <= <timestamp::Pallet<T>>::get(), Error::<T>::EventNotStarted);
+			let club =
+				PalletStorage::<T>::get(event.club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+
+			let members = club.members.len() as u32;
+			let revenue = event.revenue;
+			T::Currency::transfer(
+				&Self::treasury_account(event.club_id),
+				&Self::payout_destination(&club),
+				revenue.into(),
+				AllowDeath,
+			)?;
+
+			Escrow::<T>::mutate(event.club_id, |escrow| *escrow = escrow.saturating_sub(revenue));
+			event.revenue = 0;
+			event.status = ClubEventStatus::Settled;
+
+			ClubEvents::<T>::insert(event_id, event);
+			Self::deposit_event(Event::EventSettled { event_id, revenue });
+			Ok(Some(<T as pallet::Config>::WeightInfo::settle_event(members)).into())
+		}
+
+		/// Renew the membership like `pay_membership_expense`, spending up to `points` loyalty
+		/// points on a discount of the price.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::pay_membership_with_points(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn pay_membership_with_points(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			expense: u32,
+			points: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let (members, dependents, notified) =
+				Self::do_renew_membership(caller, club_id, expense, points)?;
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::pay_membership_with_points(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Grant loyalty points to a member, with an owner-defined reason code.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::grant_points(T::MaxMembers::get()))]
+		pub fn grant_points(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			member: T::AccountId,
+			amount: u32,
+			reason_code: u8,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+
+			Self::award_points(club_id, &member, amount, PointsReason::Owner(reason_code));
+			Ok(Some(<T as pallet::Config>::WeightInfo::grant_points(members)).into())
+		}
+
+		/// Deduct loyalty points from a member, with an owner-defined reason code.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deduct_points(T::MaxMembers::get()))]
+		pub fn deduct_points(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			member: T::AccountId,
+			amount: u32,
+			reason_code: u8,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+
+			Self::spend_points(club_id, &member, amount, PointsReason::Owner(reason_code))?;
+			Ok(Some(<T as pallet::Config>::WeightInfo::deduct_points(members)).into())
+		}
+
+		/// Choose whether new members join by paying a fee or by locking a stake. Existing
+		/// members keep the mode they joined with.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_join_mode(T::MaxMembers::get()))]
+		pub fn set_join_mode(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			mode: JoinMode<T::Moment>,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
+			ensure!(!matches!(mode, JoinMode::Stake { amount: 0, .. }), Error::<T>::ZeroPrice);
+
+			JoinModes::<T>::insert(club_id, mode);
+			Self::deposit_event(Event::JoinModeSet { club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_join_mode(members)).into())
+		}
+
+		/// Join a club in stake mode by reserving the club's stake amount on the caller's account.
+		/// Each stake is reserved separately, so the same funds cannot back stakes in several
+		/// clubs.
+		#[pallet::call_index(30)]
+		#[pallet::weight(
+			<T as pallet::Config>::WeightInfo::join_with_stake(T::MaxMembers::get())
+				.saturating_add(T::MembershipChanged::max_weight())
+		)]
+		pub fn join_with_stake(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &caller);
 
 			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
-			club.members.get(&caller).ok_or(Error::<T>::ClubDoesNotExist)?;
+			let (amount, cooldown) = match JoinModes::<T>::get(club_id) {
+				JoinMode::Stake { amount, cooldown } => (amount, cooldown),
+				JoinMode::Fee => return Err(Error::<T>::NotStakeMode.into()),
+			};
+			ensure!(!Bans::<T>::contains_key(club_id, &caller), Error::<T>::AccountBanned);
+			ensure!(
+				!club.members.contains_key(&caller) &&
+					!Dependents::<T>::contains_key(club_id, &caller),
+				Error::<T>::AlreadyMember
+			);
+			Self::ensure_room(&club)?;
+			ensure!(
+				T::Currency::can_reserve(&caller, amount.into()),
+				Error::<T>::InsufficientBalance
+			);
+
+			let members = club.members.len() as u32;
+
+			T::Currency::reserve(&caller, amount.into())?;
+			club.members.insert(caller.clone(), <timestamp::Pallet<T>>::get());
+
+			PalletStorage::<T>::insert(club_id, club);
+			Self::note_joined(club_id, &caller);
+			Stakes::<T>::insert(club_id, &caller, StakeInfo { amount, cooldown, unlock_at: None });
+			Self::deposit_event(Event::MemberAdded { member: caller.clone(), club_id });
+			Self::deposit_event(Event::Staked { member: caller, club_id, amount });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::join_with_stake(members)
+					.saturating_add(notified),
+			)
+			.into())
+		}
 
-			if club.annual_expenses * MAX_YEARS < expense {
-				return Err(Error::<T>::TooManyTokens.into())
+		/// Ask to unlock the stake. The membership lasts until the cooldown the stake was made
+		/// with has passed.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unstake())]
+		pub fn unstake(origin: OriginFor<T>, club_id: ClubId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let unlock_at = Stakes::<T>::try_mutate(club_id, &caller, |stake| {
+				let stake = stake.as_mut().ok_or(Error::<T>::NotStaked)?;
+				ensure!(stake.unlock_at.is_none(), Error::<T>::AlreadyUnlocking);
+				let unlock_at = <timestamp::Pallet<T>>::get().saturating_add(stake.cooldown);
+				stake.unlock_at = Some(unlock_at);
+				Ok::<_, DispatchError>(unlock_at)
+			})?;
+			Self::deposit_event(Event::UnlockRequested { member: caller, club_id, unlock_at });
+			Ok(())
+		}
+
+		/// Release the stake once the cooldown has passed. The account stops being a member
+		/// unless it still has paid membership time left or holds a lifetime membership.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_stake(
+			T::MaxMembers::get(),
+			T::MaxDependents::get(),
+		)
+		.saturating_add(T::MembershipChanged::max_weight()))]
+		pub fn withdraw_stake(origin: OriginFor<T>, club_id: ClubId) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let before = Self::membership_snapshot(club_id, &caller);
+
+			let stake = Stakes::<T>::get(club_id, &caller).ok_or(Error::<T>::NotStaked)?;
+			let now = <timestamp::Pallet<T>>::get();
+			ensure!(stake.unlock_at.map_or(false, |at| at <= now), Error::<T>::StakeLocked);
+
+			T::Currency::unreserve(&caller, stake.amount.into());
+			Stakes::<T>::remove(club_id, &caller);
+			PalletStorage::<T>::mutate(club_id, |club| {
+				if let Some(club) = club {
+					let expired = club.members.get(&caller).map_or(false, |expiry| *expiry <= now);
+					if expired && !LifetimeMembers::<T>::contains_key(club_id, &caller) {
+						club.members.remove(&caller);
+					}
+				}
+			});
+			let members = Self::member_count(club_id);
+			let dependents = Self::dependent_count(club_id, &caller);
+			Self::deposit_event(Event::StakeWithdrawn { member: caller, club_id });
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::withdraw_stake(members, dependents)
+					.saturating_add(notified),
+			)
+			.into())
+		}
+
+		/// Claim the refund of a ticket of a cancelled event that could not be paid out when the
+		/// event was cancelled. The refund is paid under the same existential deposit rule, so a
+		/// refund below the existential deposit fails until the holder's account exists.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_refund())]
+		pub fn claim_refund(origin: OriginFor<T>, event_id: EventId) -> DispatchResult {
+			let holder = ensure_signed(origin)?;
+
+			let mut event = ClubEvents::<T>::get(event_id).ok_or(Error::<T>::EventDoesNotExist)?;
+			let amount =
+				PendingRefunds::<T>::get(event_id, &holder).ok_or(Error::<T>::NoPendingRefund)?;
+			T::Currency::transfer(
+				&Self::treasury_account(event.club_id),
+				&holder,
+				amount.into(),
+				AllowDeath,
+			)?;
+
+			Escrow::<T>::mutate(event.club_id, |escrow| *escrow = escrow.saturating_sub(amount));
+			event.revenue = event.revenue.saturating_sub(amount);
+			ClubEvents::<T>::insert(event_id, event);
+			PendingRefunds::<T>::remove(event_id, &holder);
+			Self::deposit_event(Event::RefundClaimed { event_id, holder, amount });
+			Ok(())
+		}
+
+		/// Send the revenue of the clubs owned by the caller to `destination` instead of the
+		/// caller, or back to the caller with `None`. The destination is kept from being reaped
+		/// until it is replaced.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_payout_destination())]
+		pub fn set_payout_destination(
+			origin: OriginFor<T>,
+			destination: Option<T::AccountId>,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			let previous = Payouts::<T>::get(&account);
+			match &destination {
+				Some(destination) => {
+					frame_system::Pallet::<T>::inc_consumers_without_limit(destination)
+						.map_err(|_| Error::<T>::AccountDoesNotExist)?;
+					Payouts::<T>::insert(&account, vec![destination.clone()]);
+				},
+				None => Payouts::<T>::remove(&account),
+			}
+			for previous in previous {
+				frame_system::Pallet::<T>::dec_consumers(&previous);
 			}
+			Self::deposit_event(Event::PayoutDestinationSet { account, destination });
+			Ok(())
+		}
 
-			T::Currency::transfer(&caller, &club.owner, club.annual_expenses.into(), AllowDeath)?;
+		/// Set or clear the part of their members' transaction fees the club pays from its
+		/// treasury.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_policy(T::MaxMembers::get()))]
+		pub fn set_fee_policy(
+			origin: OriginFor<T>,
+			club_id: ClubId,
+			policy: Option<FeePolicy>,
+		) -> DispatchResultWithPostInfo {
+			let members = ensure_owner::<T>(origin, club_id)?.members.len() as u32;
 
-			let current_membership_end_moment = club.members.get(&caller).unwrap();
+			FeePolicies::<T>::set(club_id, policy);
+			Self::deposit_event(Event::FeePolicySet { club_id });
+			Ok(Some(<T as pallet::Config>::WeightInfo::set_fee_policy(members)).into())
+		}
+	}
 
-			let new_membership_end_moment = *current_membership_end_moment +
-				T::Moment::from(expense / club.annual_expenses * SECOND_IN_YEAR);
+	impl<T: Config> Pallet<T> {
+		/// Renew the membership of `caller`, spending up to `points` loyalty points on a
+		/// discount. Returns the number of members and dependents, and the weight of the
+		/// notification of the change.
+		fn do_renew_membership(
+			caller: T::AccountId,
+			club_id: ClubId,
+			expense: u32,
+			points: u32,
+		) -> Result<(u32, u32, Weight), DispatchError> {
+			let before = Self::membership_snapshot(club_id, &caller);
+			let mut club = PalletStorage::<T>::get(club_id).ok_or(Error::<T>::ClubDoesNotExist)?;
+			ensure!(club.members.contains_key(&caller), Error::<T>::NotMember);
+			ensure!(!Suspended::<T>::contains_key(club_id, &caller), Error::<T>::MemberSuspended);
+			ensure!(
+				!LifetimeMembers::<T>::contains_key(club_id, &caller),
+				Error::<T>::LifetimeMember
+			);
 
-			club.members.insert(caller.clone(), new_membership_end_moment);
+			let price = club.annual_expenses;
+			let rate = T::PointsPerDiscountUnit::get();
+			let discount = points.checked_div(rate).unwrap_or(0).min(price);
+			if discount > 0 {
+				Self::spend_points(club_id, &caller, discount * rate, PointsReason::Discount)?;
+			}
+			Self::do_pay_membership(&mut club, &caller, &caller, price, discount, expense)?;
+			let dependents = Self::dependent_count(club_id, &caller);
+			Self::dissolve_group(club_id, &caller);
+			let members = club.members.len() as u32;
 
 			PalletStorage::<T>::insert(club_id, club);
-			Self::deposit_event(Event::MembershipExpencesPayed { member: caller, club_id });
+			Self::deposit_event(Event::MembershipExpencesPayed { member: caller.clone(), club_id });
+			Self::award_renewal_points(club_id, &caller);
+			Self::convert_trial(club_id, caller);
+			let notified = Self::notify_membership_change(club_id, before);
+			Ok((members, dependents, notified))
+		}
+
+		/// Ensure `club` has room for one more member.
+		fn ensure_room(club: &Club<T::AccountId, T::Moment>) -> DispatchResult {
+			ensure!((club.members.len() as u32) < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+			Ok(())
+		}
+
+		/// Record the moment `who` joined the club, unless it is already known.
+		fn note_joined(club_id: ClubId, who: &T::AccountId) {
+			if !JoinedAt::<T>::contains_key(club_id, who) {
+				JoinedAt::<T>::insert(club_id, who, <timestamp::Pallet<T>>::get());
+			}
+		}
+
+		/// Award the renewal points, plus the tenure bonus for each full year since joining. A
+		/// member whose join time is unknown, like the members of the genesis clubs, is taken to
+		/// join now.
+		fn award_renewal_points(club_id: ClubId, who: &T::AccountId) {
+			Self::note_joined(club_id, who);
+			let now = <timestamp::Pallet<T>>::get();
+			let tenure_years: u32 = JoinedAt::<T>::get(club_id, who)
+				.and_then(|joined| {
+					now.saturating_sub(joined).checked_div(&T::MomentsPerYear::get())
+				})
+				.unwrap_or_default()
+				.saturated_into();
+			let amount = T::PointsPerRenewal::get()
+				.saturating_add(tenure_years.saturating_mul(T::PointsPerTenureYear::get()));
+			Self::award_points(club_id, who, amount, PointsReason::Renewal);
+		}
+
+		/// Add loyalty points to the balance of `who` and record them in the ledger.
+		fn award_points(club_id: ClubId, who: &T::AccountId, amount: u32, reason: PointsReason) {
+			if amount == 0 {
+				return
+			}
+			Points::<T>::mutate(club_id, who, |balance| *balance = balance.saturating_add(amount));
+			Self::record_points(club_id, who, reason, amount.into());
+			Self::deposit_event(Event::PointsAwarded {
+				club_id,
+				member: who.clone(),
+				amount,
+				reason,
+			});
+		}
+
+		/// Remove loyalty points from the balance of `who` and record them in the ledger.
+		fn spend_points(
+			club_id: ClubId,
+			who: &T::AccountId,
+			amount: u32,
+			reason: PointsReason,
+		) -> DispatchResult {
+			Points::<T>::try_mutate(club_id, who, |balance| -> DispatchResult {
+				*balance = balance.checked_sub(amount).ok_or(Error::<T>::InsufficientPoints)?;
+				Ok(())
+			})?;
+			Self::record_points(club_id, who, reason, -i64::from(amount));
+			Self::deposit_event(Event::PointsDeducted {
+				club_id,
+				member: who.clone(),
+				amount,
+				reason,
+			});
 			Ok(())
 		}
+
+		/// Append an entry to the ledger of `who`, dropping the oldest entry when it is full.
+		pub(crate) fn record_points(
+			club_id: ClubId,
+			who: &T::AccountId,
+			reason: PointsReason,
+			change: i64,
+		) {
+			let entry = PointsEntry { reason, change, at: <timestamp::Pallet<T>>::get() };
+			PointsLedger::<T>::mutate(club_id, who, |ledger| {
+				if ledger.is_full() && !ledger.is_empty() {
+					ledger.remove(0);
+				}
+				let _ = ledger.try_push(entry);
+			});
+		}
+
+		/// Charge `payer` for the whole years of membership that `expense` pays for at the annual
+		/// `price`, minus `discount`, and extend the membership of `beneficiary`, who must already
+		/// be in `club.members`. Returns the new expiry moment.
+		fn do_pay_membership(
+			club: &mut Club<T::AccountId, T::Moment>,
+			payer: &T::AccountId,
+			beneficiary: &T::AccountId,
+			price: u32,
+			discount: u32,
+			expense: u32,
+		) -> Result<T::Moment, DispatchError> {
+			let current_membership_end_moment =
+				club.members.get(beneficiary).ok_or(Error::<T>::NotMember)?;
+			ensure!(price > 0 && expense >= price, Error::<T>::TooFewTokens);
+			let new_membership_end_moment =
+				Self::renewed_end(*current_membership_end_moment, price, expense)
+					.ok_or(Error::<T>::TooManyTokens)?;
+
+			T::Currency::transfer(
+				payer,
+				&club.owner,
+				Self::renewal_charge(price, expense).saturating_sub(discount).into(),
+				AllowDeath,
+			)?;
+
+			club.members.insert(beneficiary.clone(), new_membership_end_moment);
+			Ok(new_membership_end_moment)
+		}
+
+		/// The membership time bought by paying `expense` for a membership of `price` per year,
+		/// or `None` if the price is zero or `expense` is worth more than `MAX_YEARS`.
+		pub(crate) fn paid_period(price: u32, expense: u32) -> Option<T::Moment> {
+			if price == 0 || u64::from(price) * u64::from(MAX_YEARS) < u64::from(expense) {
+				return None
+			}
+			T::MomentsPerYear::get().checked_mul(&T::Moment::from(expense / price))
+		}
+
+		/// The part of `expense` that pays for whole years of membership at `price` per year.
+		pub(crate) fn renewal_charge(price: u32, expense: u32) -> u32 {
+			expense.checked_div(price).unwrap_or(0) * price
+		}
+
+		/// The end of a membership ending at `end` after paying `expense` for it at `price` per
+		/// year, or `None` if the payment is invalid or the membership would end more than
+		/// `MAX_YEARS` from now.
+		pub(crate) fn renewed_end(end: T::Moment, price: u32, expense: u32) -> Option<T::Moment> {
+			let end = end.checked_add(&Self::paid_period(price, expense)?)?;
+			let horizon = <timestamp::Pallet<T>>::get().saturating_add(Self::max_period());
+			(end <= horizon).then_some(end)
+		}
+
+		/// The longest time a membership can be paid for in advance.
+		pub fn max_period() -> T::Moment {
+			T::MomentsPerYear::get().saturating_mul(T::Moment::from(MAX_YEARS))
+		}
+
+		/// The number of members of the club, dependents not included.
+		fn member_count(club_id: ClubId) -> u32 {
+			PalletStorage::<T>::get(club_id).map_or(0, |club| club.members.len() as u32)
+		}
+
+		/// The number of dependents attached to the group membership of `primary`.
+		fn dependent_count(club_id: ClubId, primary: &T::AccountId) -> u32 {
+			Groups::<T>::decode_len(club_id, primary).unwrap_or_default() as u32
+		}
+
+		/// Detach all dependents of `primary`, whose membership was renewed at the individual
+		/// price and no longer covers a group.
+		fn dissolve_group(club_id: ClubId, primary: &T::AccountId) {
+			if !Groups::<T>::contains_key(club_id, primary) {
+				return
+			}
+			for dependent in Groups::<T>::take(club_id, primary) {
+				Dependents::<T>::remove(club_id, &dependent);
+			}
+			Self::deposit_event(Event::GroupDissolved { primary: primary.clone(), club_id });
+		}
+
+		/// Mark a started trial as converted into a paid membership.
+		fn convert_trial(club_id: ClubId, member: T::AccountId) {
+			if Trials::<T>::get(club_id, &member) == Some(TrialStatus::Started) {
+				Trials::<T>::insert(club_id, &member, TrialStatus::Converted);
+				Self::deposit_event(Event::TrialConverted { member, club_id });
+			}
+		}
+
+		/// The moment a membership ends, or `None` if `who` is not an active member of the club.
+		/// Suspended members are not treated as members, and dependents share the expiry of
+		/// their primary member.
+		pub fn membership_expiry(club_id: ClubId, who: &T::AccountId) -> Option<T::Moment> {
+			Self::expiry_in(club_id, &PalletStorage::<T>::get(club_id)?, who)
+		}
+
+		/// The moment a membership of `club` ends, like `membership_expiry` but without reading
+		/// the club from storage again.
+		fn expiry_in(
+			club_id: ClubId,
+			club: &Club<T::AccountId, T::Moment>,
+			who: &T::AccountId,
+		) -> Option<T::Moment> {
+			if Suspended::<T>::contains_key(club_id, who) {
+				return None
+			}
+			match Dependents::<T>::get(club_id, who) {
+				Some(primary) => Self::expiry_in(club_id, club, &primary),
+				None => club.members.get(who).copied(),
+			}
+		}
+
+		/// Whether `who` holds a lifetime membership. Dependents only share the paid expiry of
+		/// their primary member, never its lifetime membership.
+		pub fn is_lifetime_member(club_id: ClubId, who: &T::AccountId) -> bool {
+			!Suspended::<T>::contains_key(club_id, who) &&
+				LifetimeMembers::<T>::contains_key(club_id, who)
+		}
+
+		/// Whether `who` holds a stake in the club that is not unlocked by `moment`.
+		pub fn is_staked_member_at(club_id: ClubId, who: &T::AccountId, moment: T::Moment) -> bool {
+			!Suspended::<T>::contains_key(club_id, who) &&
+				Stakes::<T>::get(club_id, who)
+					.map_or(false, |stake| stake.unlock_at.map_or(true, |at| at > moment))
+		}
+
+		/// Whether `who` is a member of the club whose membership has not expired yet.
+		pub fn is_active_member(club_id: ClubId, who: &T::AccountId) -> bool {
+			Self::is_member_at(club_id, who, <timestamp::Pallet<T>>::get())
+		}
+
+		/// The account holding funds on behalf of the club, such as ticket revenue that may
+		/// still be refunded.
+		pub fn treasury_account(club_id: ClubId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(club_id)
+		}
+
+		/// The part of the club's treasury that can sponsor fees: everything but the `Escrow`
+		/// and the existential deposit of the treasury account.
+		pub fn sponsorable_balance(club_id: ClubId) -> BalanceOf<T> {
+			T::Currency::free_balance(&Self::treasury_account(club_id))
+				.saturating_sub(T::Currency::minimum_balance())
+				.saturating_sub(Escrow::<T>::get(club_id).into())
+		}
+
+		/// The account that receives the club's revenue: the payout destination of the owner,
+		/// or the owner itself.
+		pub fn payout_destination(club: &Club<T::AccountId, T::Moment>) -> T::AccountId {
+			Payouts::<T>::get(&club.owner)
+				.first()
+				.cloned()
+				.unwrap_or_else(|| club.owner.clone())
+		}
+
+		/// Whether `who` will still be a member of the club at `moment`.
+		pub fn is_member_at(club_id: ClubId, who: &T::AccountId, moment: T::Moment) -> bool {
+			Self::is_member_of(club_id, PalletStorage::<T>::get(club_id).as_ref(), who, moment)
+		}
+
+		/// Whether `who` will still be a member of `club` at `moment`. Checking many accounts
+		/// against a club read once keeps the cost linear in the size of the club.
+		fn is_member_of(
+			club_id: ClubId,
+			club: Option<&Club<T::AccountId, T::Moment>>,
+			who: &T::AccountId,
+			moment: T::Moment,
+		) -> bool {
+			Self::is_lifetime_member(club_id, who) ||
+				Self::is_staked_member_at(club_id, who, moment) ||
+				club.and_then(|club| Self::expiry_in(club_id, club, who))
+					.map_or(false, |expiry| expiry > moment)
+		}
+
+		/// The active members of the club, including dependents, sorted.
+		pub fn active_members(club_id: ClubId) -> Vec<T::AccountId> {
+			let Some(club) = PalletStorage::<T>::get(club_id) else { return Vec::new() };
+			let now = <timestamp::Pallet<T>>::get();
+			let mut members: Vec<T::AccountId> = club
+				.members
+				.keys()
+				.cloned()
+				.chain(Dependents::<T>::iter_key_prefix(club_id))
+				.filter(|who| Self::is_member_of(club_id, Some(&club), who, now))
+				.collect();
+			members.sort();
+			members.dedup();
+			members
+		}
+
+		/// Whether `who` and its dependents are active members, to be compared after a change.
+		fn membership_snapshot(club_id: ClubId, who: &T::AccountId) -> Vec<(T::AccountId, bool)> {
+			let club = PalletStorage::<T>::get(club_id);
+			let now = <timestamp::Pallet<T>>::get();
+			sp_std::iter::once(who.clone())
+				.chain(Groups::<T>::get(club_id, who))
+				.map(|account| {
+					let active = Self::is_member_of(club_id, club.as_ref(), &account, now);
+					(account, active)
+				})
+				.collect()
+		}
+
+		/// Notify `T::MembershipChanged` of the accounts in `before` whose status changed, and
+		/// return the weight of the notification.
+		fn notify_membership_change(club_id: ClubId, before: Vec<(T::AccountId, bool)>) -> Weight {
+			let club = PalletStorage::<T>::get(club_id);
+			let now = <timestamp::Pallet<T>>::get();
+			let mut incoming = Vec::new();
+			let mut outgoing = Vec::new();
+			for (account, was_active) in before {
+				match (was_active, Self::is_member_of(club_id, club.as_ref(), &account, now)) {
+					(false, true) => incoming.push(account),
+					(true, false) => outgoing.push(account),
+					_ => {},
+				}
+			}
+			if incoming.is_empty() && outgoing.is_empty() {
+				return Weight::zero()
+			}
+			incoming.sort();
+			outgoing.sort();
+			T::MembershipChanged::on_membership_changed(club_id, &incoming, &outgoing)
+		}
 	}
 }
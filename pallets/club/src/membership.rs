@@ -0,0 +1,115 @@
+//! Adapters exposing the active members of a club to other pallets.
+//!
+//! [`ClubMembers`] implements [`Contains`] and [`SortedMembers`] for a single club, so that it
+//! can be used as the member set of pallets such as `pallet_tips`. [`ChangeClubMembers`]
+//! forwards membership changes of that club to a [`ChangeMembers`] implementation, such as
+//! `pallet_collective`, when plugged in as the pallet's `MembershipChanged` handler.
+//!
+//! Memberships also expire with time, without any call to the pallet. [`ClubMembers`] always
+//! reflects expiries, but [`ChangeClubMembers`] only reports the changes made by calls, so an
+//! expired member stays in the consuming pallet until the next change of the club. Consumers
+//! that act on the forwarded member set must check [`ClubMembers`] before relying on a member,
+//! for example with an `EnsureOrigin` built on it.
+
+use crate::{weights::WeightInfo, ClubId, Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+	traits::{ChangeMembers, Contains, Get, InitializeMembers, SortedMembers},
+	weights::Weight,
+};
+use sp_std::vec::Vec;
+
+/// Handler for accounts becoming or stopping being active members of a club.
+pub trait OnMembershipChanged<AccountId> {
+	/// `incoming` became active members of the club and `outgoing` stopped being active
+	/// members. Both are sorted. Returns the weight consumed, at most [`Self::max_weight`].
+	fn on_membership_changed(
+		club_id: ClubId,
+		incoming: &[AccountId],
+		outgoing: &[AccountId],
+	) -> Weight;
+
+	/// The maximum weight of [`Self::on_membership_changed`], charged up front by every call
+	/// that may change memberships.
+	fn max_weight() -> Weight;
+}
+
+impl<AccountId> OnMembershipChanged<AccountId> for () {
+	fn on_membership_changed(_: ClubId, _: &[AccountId], _: &[AccountId]) -> Weight {
+		Weight::zero()
+	}
+
+	fn max_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The active members of the club returned by `C`.
+pub struct ClubMembers<T, C>(PhantomData<(T, C)>);
+
+impl<T: Config, C: Get<ClubId>> Contains<T::AccountId> for ClubMembers<T, C> {
+	fn contains(who: &T::AccountId) -> bool {
+		Pallet::<T>::is_active_member(C::get(), who)
+	}
+}
+
+impl<T: Config, C: Get<ClubId>> SortedMembers<T::AccountId> for ClubMembers<T, C> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		Pallet::<T>::active_members(C::get())
+	}
+
+	fn contains(who: &T::AccountId) -> bool {
+		Pallet::<T>::is_active_member(C::get(), who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &T::AccountId) {
+		let club_id = C::get();
+		crate::PalletStorage::<T>::mutate(club_id, |club| {
+			let club = club.get_or_insert_with(|| crate::Club {
+				owner: who.clone(),
+				annual_expenses: 1,
+				members: Default::default(),
+			});
+			club.members.insert(who.clone(), Default::default());
+		});
+		crate::LifetimeMembers::<T>::insert(club_id, who, ());
+	}
+}
+
+/// Forwards membership changes of the club returned by `C` to `M`. Expiries are not forwarded
+/// until the next change of the club, see the module documentation.
+///
+/// Each change rebuilds the active member set, which is weighed for a club of `MaxMembers`
+/// members with `MaxDependents` dependents each. `ChangeMembers` implementations are not
+/// weighed, so the cost of `M` itself is not included.
+pub struct ChangeClubMembers<T, C, M>(PhantomData<(T, C, M)>);
+
+impl<T: Config, C: Get<ClubId>, M: ChangeMembers<T::AccountId>> OnMembershipChanged<T::AccountId>
+	for ChangeClubMembers<T, C, M>
+{
+	fn on_membership_changed(
+		club_id: ClubId,
+		incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+	) -> Weight {
+		if club_id != C::get() {
+			return Weight::zero()
+		}
+		M::change_members_sorted(incoming, outgoing, &Pallet::<T>::active_members(club_id));
+		Self::max_weight()
+	}
+
+	fn max_weight() -> Weight {
+		let members = T::MaxMembers::get();
+		T::WeightInfo::active_members(members, members.saturating_mul(T::MaxDependents::get()))
+	}
+}
+
+impl<T: Config, C: Get<ClubId>, M: InitializeMembers<T::AccountId>> ChangeClubMembers<T, C, M> {
+	/// Hand the current active members of the club to `M`, for example when the consuming
+	/// pallet is added to a running chain.
+	pub fn initialize_members() {
+		M::initialize_members(&Pallet::<T>::active_members(C::get()));
+	}
+}
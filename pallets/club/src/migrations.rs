@@ -0,0 +1,176 @@
+//! Storage migrations of the club pallet.
+//!
+//! Each migration upgrades the storage from one [`StorageVersion`] to the next and is wrapped in
+//! a [`VersionedUpgrade`], so that it runs only on storage of the version it upgrades from.
+//! Runtimes list the migrations they need in the migrations of their `Executive`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Runs the migration `Inner` if the on-chain storage version of the pallet is `FROM`, and then
+/// sets the version to `TO`. Does nothing for any other version.
+pub struct VersionedUpgrade<const FROM: u16, const TO: u16, Inner, T>(PhantomData<(Inner, T)>);
+
+impl<const FROM: u16, const TO: u16, Inner: OnRuntimeUpgrade, T: Config> OnRuntimeUpgrade
+	for VersionedUpgrade<FROM, TO, Inner, T>
+{
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != FROM {
+			return T::DbWeight::get().reads(1)
+		}
+		let weight = Inner::on_runtime_upgrade();
+		StorageVersion::new(TO).put::<Pallet<T>>();
+		weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let state = if Pallet::<T>::on_chain_storage_version() == FROM {
+			Some(Inner::pre_upgrade()?)
+		} else {
+			None
+		};
+		Ok(state.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let state = Option::<Vec<u8>>::decode(&mut &state[..])
+			.map_err(|_| "the pre-upgrade state does not decode")?;
+		if let Some(state) = state {
+			Inner::post_upgrade(state)?;
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == TO,
+				"the storage version was not updated"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Version 1 is the first tracked version of the storage. It keeps the layout of the clubs and
+/// payouts of the untracked version 0; all other storage items are new in version 1.
+pub mod v1 {
+	use super::*;
+
+	/// Upgrade the storage from version 0 to version 1.
+	pub type MigrateToV1<T> = VersionedUpgrade<0, 1, UncheckedMigrateToV1<T>, T>;
+
+	/// The length of a paid year in storage version 0, whatever the unit of `Moment`.
+	const V0_MOMENTS_PER_YEAR: u32 = 31_622_400;
+
+	/// The data part of [`MigrateToV1`], without the version checks.
+	///
+	/// Version 0 paid years of `V0_MOMENTS_PER_YEAR`, which is less than nine hours of
+	/// millisecond timestamps. The paid time left of each membership is converted to years of
+	/// `T::MomentsPerYear`, up to the longest period that can be paid in advance. Memberships
+	/// that have ended are left as they are.
+	///
+	/// The join times of zero recorded for the members of the genesis clubs are removed, so that
+	/// their tenure counts from their next renewal instead of from the epoch.
+	///
+	/// Each club owner gets a consumer reference for each of its clubs, so that it can no longer
+	/// be reaped. An owner reaped before the upgrade cannot get one and is left as it is.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut joined = 0u64;
+			let mut cleared = 0u64;
+			JoinedAt::<T>::translate::<T::Moment, _>(|_, _, at| {
+				joined += 1;
+				cleared += at.is_zero() as u64;
+				(!at.is_zero()).then_some(at)
+			});
+
+			let now = <timestamp::Pallet<T>>::get();
+			let horizon = now.saturating_add(Pallet::<T>::max_period());
+			let mut owners = 0u64;
+			PalletStorage::<T>::translate::<Club<T::AccountId, T::Moment>, _>(|_, mut club| {
+				owners += 1;
+				let _ = frame_system::Pallet::<T>::inc_consumers_without_limit(&club.owner);
+				for end in club.members.values_mut().filter(|end| **end > now) {
+					*end = now.saturating_add(rescale::<T>(*end - now)).min(horizon);
+				}
+				Some(club)
+			});
+
+			T::DbWeight::get().reads_writes(joined + 2 * owners + 1, cleared + 2 * owners)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let mut clubs = BTreeMap::<T::AccountId, u32>::new();
+			for club in PalletStorage::<T>::iter_values() {
+				*clubs.entry(club.owner).or_default() += 1;
+			}
+			let consumers: Vec<(T::AccountId, u32, u32)> = clubs
+				.into_iter()
+				.map(|(owner, clubs)| {
+					let consumers = frame_system::Pallet::<T>::consumers(&owner);
+					(owner, clubs, consumers)
+				})
+				.collect();
+			Ok((consumers, active_memberships::<T>()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (owners, active) = <(Vec<(T::AccountId, u32, u32)>, u32)>::decode(&mut &state[..])
+				.map_err(|_| "the pre-upgrade state does not decode")?;
+			let mut clubs = 0u32;
+			for club_id in PalletStorage::<T>::iter_keys() {
+				ensure!(PalletStorage::<T>::try_get(club_id).is_ok(), "a club does not decode");
+				clubs += 1;
+			}
+			ensure!(
+				clubs == owners.iter().map(|(_, clubs, _)| clubs).sum::<u32>(),
+				"the number of clubs changed"
+			);
+			for (owner, clubs, consumers) in owners {
+				if frame_system::Pallet::<T>::providers(&owner) > 0 {
+					ensure!(
+						frame_system::Pallet::<T>::consumers(&owner) == consumers + clubs,
+						"an owner did not get a consumer reference for each of its clubs"
+					);
+				}
+			}
+			ensure!(
+				JoinedAt::<T>::iter_values().all(|at| !at.is_zero()),
+				"a join time of zero is left"
+			);
+			ensure!(
+				active_memberships::<T>() == active,
+				"the number of active memberships changed"
+			);
+			Ok(())
+		}
+	}
+
+	/// Convert `remaining` paid time from years of `V0_MOMENTS_PER_YEAR` to years of
+	/// `T::MomentsPerYear`, in two steps so that it does not overflow.
+	fn rescale<T: Config>(remaining: T::Moment) -> T::Moment {
+		let v0_year = T::Moment::from(V0_MOMENTS_PER_YEAR);
+		let year = T::MomentsPerYear::get();
+		(remaining / v0_year)
+			.saturating_mul(year)
+			.saturating_add((remaining % v0_year).saturating_mul(year) / v0_year)
+	}
+
+	/// The number of memberships of all clubs that have not ended yet.
+	#[cfg(feature = "try-runtime")]
+	fn active_memberships<T: Config>() -> u32 {
+		let now = <timestamp::Pallet<T>>::get();
+		PalletStorage::<T>::iter_values()
+			.map(|club| club.members.values().filter(|end| **end > now).count() as u32)
+			.sum()
+	}
+}
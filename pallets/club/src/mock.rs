@@ -1,19 +1,24 @@
 use crate as pallet_club;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ChangeMembers, ConstU16, ConstU32, ConstU64, ConstU8, GenesisBuild},
+	weights::FixedFee,
+	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 parameter_types! {
 	pub const MinimumPeriod: u64 = 5;
+	pub const ClubPalletId: PalletId = PalletId(*b"py/clubs");
+	pub static ExistentialDeposit: u64 = 1;
 }
 
 // Configure a mock runtime to test the pallet.
@@ -24,8 +29,10 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_club,
 		Timestamp: pallet_timestamp,
+		TransactionPayment: pallet_transaction_payment,
 	}
 );
 
@@ -47,7 +54,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -56,11 +63,64 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type HoldIdentifier = ();
+	type MaxHolds = ();
+}
+
+/// Every transaction costs `3 * FEE_UNIT`: the base fee, the length fee and the weight fee.
+pub const FEE_UNIT: u64 = 10;
+
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = FixedFee<{ FEE_UNIT as u32 }, u64>;
+	type LengthToFee = FixedFee<{ FEE_UNIT as u32 }, u64>;
+	type FeeMultiplierUpdate = ();
+}
+
 impl pallet_club::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 
-	type Currency = ();
+	type Currency = Balances;
+	type PalletId = ClubPalletId;
+	type MaxEventCapacity = ConstU32<100>;
+	type MaxMembers = ConstU32<5>;
+	type MaxDependents = ConstU32<4>;
+	type PointsPerRenewal = ConstU32<10>;
+	type PointsPerTenureYear = ConstU32<5>;
+	type PointsPerAttendance = ConstU32<3>;
+	type PointsPerReferral = ConstU32<20>;
+	type PointsPerDiscountUnit = ConstU32<10>;
+	type MaxLedgerEntries = ConstU32<4>;
+	type RenewalPriority = ConstU64<100>;
+	type MomentsPerYear = ConstU64<YEAR>;
+	type MembershipChanged = pallet_club::ChangeClubMembers<Test, ConstU32<5>, TestChangeMembers>;
+}
+
+thread_local! {
+	pub static MEMBERS: RefCell<Vec<u64>> = RefCell::new(vec![]);
+}
+
+/// Records the member set of club 5 as a consuming pallet would.
+pub struct TestChangeMembers;
+impl ChangeMembers<u64> for TestChangeMembers {
+	fn change_members_sorted(_incoming: &[u64], _outgoing: &[u64], new: &[u64]) {
+		MEMBERS.with(|m| *m.borrow_mut() = new.to_vec());
+	}
 }
 
 impl pallet_timestamp::Config for Test {
@@ -73,7 +133,35 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+/// The accounts endowed with `ENDOWMENT` at genesis.
+pub const ACCOUNTS: [u64; 4] = [56, 57, 58, 59];
+pub const ENDOWMENT: u64 = 1_000;
+
+/// A year of 366 days in milliseconds, the unit of the timestamp pallet's `Moment`.
+pub const YEAR: u64 = 31_622_400_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	// Tests running on the same thread share the existential deposit.
+	ExistentialDeposit::set(1);
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: ACCOUNTS.iter().map(|who| (*who, ENDOWMENT)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
+}
+
+/// Move the clock of the mock runtime forward by `time`.
+pub fn advance_time(time: u64) {
+	Timestamp::set_timestamp(Timestamp::get() + time);
+}
+
+/// Run `test` in new test externalities, then check the invariants of the pallet.
+pub fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		TemplateModule::do_try_state().unwrap();
+	})
 }
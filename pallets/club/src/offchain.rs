@@ -0,0 +1,176 @@
+//! Offchain index of club memberships.
+//!
+//! The offchain worker keeps an index in the node's persistent offchain storage, so that a node
+//! can answer queries such as "all clubs of an account" without an on-chain reverse map. Each
+//! block, the clubs whose membership changed according to the block's events are re-indexed
+//! from state. The index remembers the number and hash of the block it was last brought up to
+//! date with. Unless that block is the parent of the current one, because blocks were skipped
+//! while syncing or the chain switched forks, every club is re-indexed.
+//!
+//! The index holds, under the keys built by the functions below:
+//! - the accounts registered with each club, dependents included, sorted;
+//! - the clubs of each account, sorted;
+//! - the upcoming membership expiries of all clubs, soonest first. Lifetime and stake memberships
+//!   do not expire and are left out.
+
+use crate::{ClubId, Config, Dependents, Event, Pallet, PalletStorage};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::{
+		offchain::{
+			storage::StorageValueRef,
+			storage_lock::{StorageLock, Time},
+		},
+		traits::{One, Saturating},
+	},
+	RuntimeDebug,
+};
+use pallet_timestamp as timestamp;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+const INDEX_PREFIX: &[u8] = b"club::index::";
+const HEAD_KEY: &[u8] = b"club::index::head";
+const LOCK_KEY: &[u8] = b"club::index::lock";
+
+/// The key of the sorted accounts registered with `club_id`.
+pub fn members_key(club_id: ClubId) -> Vec<u8> {
+	[INDEX_PREFIX, b"members::", &club_id.encode()].concat()
+}
+
+/// The key of the sorted clubs of `who`.
+pub fn clubs_key<AccountId: Encode>(who: &AccountId) -> Vec<u8> {
+	[INDEX_PREFIX, b"clubs::", &who.encode()].concat()
+}
+
+/// The key of the upcoming expiries, soonest first.
+pub const EXPIRIES_KEY: &[u8] = b"club::index::expiries";
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct UpcomingExpiry<AccountId, Moment> {
+	pub expires_at: Moment,
+	pub club_id: ClubId,
+	pub member: AccountId,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Bring the offchain index up to date with `block_number`.
+	pub(crate) fn update_index(block_number: T::BlockNumber) {
+		let mut lock = StorageLock::<Time>::new(LOCK_KEY);
+		let _guard = lock.lock();
+
+		let head = StorageValueRef::persistent(HEAD_KEY);
+		let last = head.get::<(T::BlockNumber, T::Hash)>().ok().flatten();
+		let parent =
+			(block_number.saturating_sub(One::one()), frame_system::Pallet::<T>::parent_hash());
+		let mut clubs: Vec<ClubId> = if last == Some(parent) {
+			frame_system::Pallet::<T>::read_events_no_consensus()
+				.filter_map(|record| {
+					let event = <T as Config>::RuntimeEvent::from(record.event);
+					Self::membership_club(event.try_into().ok()?)
+				})
+				.collect()
+		} else {
+			PalletStorage::<T>::iter_keys().collect()
+		};
+		clubs.sort();
+		clubs.dedup();
+
+		let now = <timestamp::Pallet<T>>::get();
+		let expiries = StorageValueRef::persistent(EXPIRIES_KEY);
+		let mut upcoming: Vec<UpcomingExpiry<T::AccountId, T::Moment>> =
+			expiries.get().ok().flatten().unwrap_or_default();
+		upcoming
+			.retain(|entry| entry.expires_at > now && clubs.binary_search(&entry.club_id).is_err());
+		for club_id in clubs {
+			Self::index_club(club_id, now, &mut upcoming);
+		}
+		upcoming.sort_by(|a, b| a.expires_at.cmp(&b.expires_at));
+
+		expiries.set(&upcoming);
+		head.set(&(block_number, frame_system::Pallet::<T>::block_hash(block_number)));
+	}
+
+	/// Re-index the members of `club_id`, adding their expiries after `now` to `upcoming`.
+	fn index_club(
+		club_id: ClubId,
+		now: T::Moment,
+		upcoming: &mut Vec<UpcomingExpiry<T::AccountId, T::Moment>>,
+	) {
+		let mut members: Vec<T::AccountId> = PalletStorage::<T>::get(club_id)
+			.map(|club| club.members.into_keys().collect())
+			.unwrap_or_default();
+		members.extend(Dependents::<T>::iter_key_prefix(club_id));
+		members.sort();
+		members.dedup();
+
+		let key = members_key(club_id);
+		let indexed = StorageValueRef::persistent(&key);
+		let old: Vec<T::AccountId> = indexed.get().ok().flatten().unwrap_or_default();
+		for who in old.iter().filter(|who| members.binary_search(who).is_err()) {
+			Self::update_clubs_of(who, |clubs| {
+				if let Ok(i) = clubs.binary_search(&club_id) {
+					clubs.remove(i);
+				}
+			});
+		}
+		for who in members.iter().filter(|who| old.binary_search(who).is_err()) {
+			Self::update_clubs_of(who, |clubs| {
+				if let Err(i) = clubs.binary_search(&club_id) {
+					clubs.insert(i, club_id);
+				}
+			});
+		}
+
+		for who in &members {
+			if Self::is_lifetime_member(club_id, who) ||
+				Self::is_staked_member_at(club_id, who, now)
+			{
+				continue
+			}
+			if let Some(expires_at) = Self::membership_expiry(club_id, who).filter(|at| *at > now) {
+				upcoming.push(UpcomingExpiry { expires_at, club_id, member: who.clone() });
+			}
+		}
+		indexed.set(&members);
+	}
+
+	fn update_clubs_of(who: &T::AccountId, f: impl FnOnce(&mut Vec<ClubId>)) {
+		let key = clubs_key(who);
+		let mut entry = StorageValueRef::persistent(&key);
+		let mut clubs: Vec<ClubId> = entry.get().ok().flatten().unwrap_or_default();
+		f(&mut clubs);
+		if clubs.is_empty() {
+			entry.clear();
+		} else {
+			entry.set(&clubs);
+		}
+	}
+
+	/// The club whose membership `event` changes, if any. Creating a club replaces any club of
+	/// the same id, dropping its members.
+	fn membership_club(event: Event<T>) -> Option<ClubId> {
+		match event {
+			Event::ClubCreated { club_id } |
+			Event::MemberAdded { club_id, .. } |
+			Event::MembershipExpencesPayed { club_id, .. } |
+			Event::MemberSuspended { club_id, .. } |
+			Event::MemberUnsuspended { club_id, .. } |
+			Event::AccountBanned { club_id, .. } |
+			Event::MembershipGifted { club_id, .. } |
+			Event::GroupMembershipPayed { club_id, .. } |
+			Event::DependentAdded { club_id, .. } |
+			Event::DependentRemoved { club_id, .. } |
+			Event::TrialStarted { club_id, .. } |
+			Event::LifetimeMembershipPayed { club_id, .. } |
+			Event::Staked { club_id, .. } |
+			Event::UnlockRequested { club_id, .. } |
+			Event::StakeWithdrawn { club_id, .. } => Some(club_id),
+			_ => None,
+		}
+	}
+}
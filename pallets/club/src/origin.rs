@@ -0,0 +1,69 @@
+//! Origins that let other pallets gate their calls on club membership or ownership.
+
+use crate::{ClubId, Config, Pallet, PalletStorage};
+use core::marker::PhantomData;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+
+/// Ensures the origin is signed by an active member of the club returned by `C`. Succeeds with
+/// the member's account.
+pub struct EnsureClubMember<T, C>(PhantomData<(T, C)>);
+
+impl<T: Config, C: Get<ClubId>> EnsureOrigin<T::RuntimeOrigin> for EnsureClubMember<T, C> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) if Pallet::<T>::is_active_member(C::get(), &who) => Ok(who),
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		use crate::ClubMembers;
+		use frame_support::traits::SortedMembers;
+
+		let who: T::AccountId = frame_benchmarking::account("club_member", 0, 0);
+		ClubMembers::<T, C>::add(&who);
+		Ok(RawOrigin::Signed(who).into())
+	}
+}
+
+/// Ensures the origin is signed by the owner of the club returned by `C`. Succeeds with the
+/// owner's account.
+pub struct EnsureClubOwner<T, C>(PhantomData<(T, C)>);
+
+impl<T: Config, C: Get<ClubId>> EnsureOrigin<T::RuntimeOrigin> for EnsureClubOwner<T, C> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who)
+				if PalletStorage::<T>::get(C::get()).map_or(false, |club| club.owner == who) =>
+				Ok(who),
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		let club_id = C::get();
+		let owner = match PalletStorage::<T>::get(club_id) {
+			Some(club) => club.owner,
+			None => {
+				let owner: T::AccountId = frame_benchmarking::account("club_owner", 0, 0);
+				PalletStorage::<T>::insert(
+					club_id,
+					crate::Club {
+						owner: owner.clone(),
+						annual_expenses: 1,
+						members: Default::default(),
+					},
+				);
+				owner
+			},
+		};
+		Ok(RawOrigin::Signed(owner).into())
+	}
+}
@@ -0,0 +1,273 @@
+//! Property-based tests: random sequences of calls are dispatched against the mock runtime, and
+//! properties of the pallet are checked after each of them.
+
+use crate::{
+	mock::*, AdmissionPolicies, AdmissionPolicy, BalanceOf, Call, ClubId, EventId, FeePolicies,
+	FeePolicy, FeeScope, GroupTier, GroupTiers, JoinMode, JoinModes, LifetimePrices, PalletStorage,
+	TrialLengths,
+};
+use codec::Encode;
+use frame_support::traits::Currency;
+use proptest::{option, prelude::*, sample::select};
+use sp_runtime::{traits::Dispatchable, Percent};
+use std::collections::{BTreeMap, BTreeSet};
+
+const CLUBS: [ClubId; 2] = [0, 1];
+
+/// A step of a generated sequence.
+#[derive(Clone, Debug)]
+enum Op {
+	/// Dispatch the call, signed by the account or as root.
+	Dispatch(Option<u64>, Call<Test>),
+	/// Let time pass.
+	Wait(u64),
+}
+
+fn account() -> impl Strategy<Value = u64> {
+	select(ACCOUNTS.to_vec())
+}
+
+fn club() -> impl Strategy<Value = ClubId> {
+	select(CLUBS.to_vec())
+}
+
+/// Event ids, of which the generated sequences create the first few.
+fn event() -> impl Strategy<Value = EventId> {
+	0..4u32
+}
+
+/// Amounts of money, biased towards the edges.
+fn amount() -> impl Strategy<Value = u32> {
+	prop_oneof![Just(0), Just(1), Just(u32::MAX), 1..500u32, any::<u32>()]
+}
+
+/// Lengths of time, biased towards the edges.
+fn moment() -> impl Strategy<Value = u64> {
+	prop_oneof![Just(0), Just(u64::MAX), 0..10 * YEAR, any::<u64>()]
+}
+
+fn owner_call() -> impl Strategy<Value = Call<Test>> {
+	prop_oneof![
+		(account(), club())
+			.prop_map(|(new_owner, club_id)| Call::transfer_ownership { new_owner, club_id }),
+		(club(), amount())
+			.prop_map(|(club_id, expense)| Call::set_annual_expense { club_id, expense }),
+		(club(), account()).prop_map(|(club_id, member)| Call::add_member { club_id, member }),
+		(club(), account()).prop_map(|(club_id, member)| Call::suspend_member { club_id, member }),
+		(club(), account())
+			.prop_map(|(club_id, member)| Call::unsuspend_member { club_id, member }),
+		(club(), account()).prop_map(|(club_id, account)| Call::ban_account {
+			club_id,
+			account,
+			reason: None
+		}),
+		(club(), any::<bool>()).prop_map(|(club_id, open)| Call::set_admission_policy {
+			club_id,
+			policy: if open { AdmissionPolicy::Open } else { AdmissionPolicy::OwnerOnly },
+		}),
+		(club(), option::of((amount(), 0..4u32))).prop_map(|(club_id, tier)| {
+			let tier = tier.map(|(annual_expenses, max_dependents)| GroupTier {
+				annual_expenses,
+				max_dependents,
+			});
+			Call::set_group_tier { club_id, tier }
+		}),
+		(club(), option::of(moment()))
+			.prop_map(|(club_id, length)| Call::set_trial_length { club_id, length }),
+		(club(), option::of(amount()))
+			.prop_map(|(club_id, price)| Call::set_lifetime_price { club_id, price }),
+	]
+}
+
+fn member_call() -> impl Strategy<Value = Call<Test>> {
+	prop_oneof![
+		(club(), amount())
+			.prop_map(|(club_id, expense)| Call::pay_membership_expense { club_id, expense }),
+		(club(), account(), amount()).prop_map(|(club_id, beneficiary, amount)| {
+			Call::gift_membership { club_id, beneficiary, amount }
+		}),
+		(club(), amount())
+			.prop_map(|(club_id, expense)| Call::pay_group_membership { club_id, expense }),
+		(club(), account())
+			.prop_map(|(club_id, dependent)| Call::add_dependent { club_id, dependent }),
+		club().prop_map(|club_id| Call::start_trial { club_id }),
+		(club(), account())
+			.prop_map(|(club_id, dependent)| Call::remove_dependent { club_id, dependent }),
+		club().prop_map(|club_id| Call::pay_lifetime_membership { club_id }),
+		club().prop_map(|club_id| Call::join_with_stake { club_id }),
+		club().prop_map(|club_id| Call::unstake { club_id }),
+		club().prop_map(|club_id| Call::withdraw_stake { club_id }),
+	]
+}
+
+fn event_call() -> impl Strategy<Value = Call<Test>> {
+	prop_oneof![
+		(club(), 0..4u32, amount(), amount(), moment()).prop_map(
+			|(club_id, capacity, member_price, guest_price, start)| Call::create_event {
+				club_id,
+				capacity,
+				member_price,
+				guest_price,
+				start,
+			}
+		),
+		event().prop_map(|event_id| Call::buy_ticket { event_id }),
+		(event(), account()).prop_map(|(event_id, holder)| Call::check_in { event_id, holder }),
+		event().prop_map(|event_id| Call::cancel_event { event_id }),
+		event().prop_map(|event_id| Call::settle_event { event_id }),
+		event().prop_map(|event_id| Call::claim_refund { event_id }),
+	]
+}
+
+fn points_call() -> impl Strategy<Value = Call<Test>> {
+	prop_oneof![
+		(club(), account(), amount(), any::<u8>()).prop_map(
+			|(club_id, member, amount, reason_code)| Call::grant_points {
+				club_id,
+				member,
+				amount,
+				reason_code,
+			}
+		),
+		(club(), account(), amount(), any::<u8>()).prop_map(
+			|(club_id, member, amount, reason_code)| Call::deduct_points {
+				club_id,
+				member,
+				amount,
+				reason_code,
+			}
+		),
+		(club(), amount(), amount()).prop_map(|(club_id, expense, points)| {
+			Call::pay_membership_with_points { club_id, expense, points }
+		}),
+	]
+}
+
+/// Calls deciding how members pay, who pays the fees of their calls and who is paid.
+fn payment_call() -> impl Strategy<Value = Call<Test>> {
+	prop_oneof![
+		(club(), amount(), moment()).prop_map(|(club_id, amount, cooldown)| {
+			Call::set_join_mode { club_id, mode: JoinMode::Stake { amount, cooldown } }
+		}),
+		(club(), option::of((0..=100u8, any::<bool>()))).prop_map(|(club_id, policy)| {
+			let policy = policy.map(|(discount, all_calls)| FeePolicy {
+				discount: Percent::from_percent(discount),
+				scope: if all_calls { FeeScope::AllCalls } else { FeeScope::ClubCalls },
+			});
+			Call::set_fee_policy { club_id, policy }
+		}),
+		option::of(account()).prop_map(|destination| Call::set_payout_destination { destination }),
+	]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+	prop_oneof![
+		1 => (account(), club(), amount()).prop_map(|(owner, club_id, annual_expenses)| {
+			Op::Dispatch(None, Call::create_club { owner, club_id, annual_expenses })
+		}),
+		4 => (account(), owner_call()).prop_map(|(signer, call)| Op::Dispatch(Some(signer), call)),
+		4 => (account(), member_call()).prop_map(|(signer, call)| Op::Dispatch(Some(signer), call)),
+		3 => (account(), event_call()).prop_map(|(signer, call)| Op::Dispatch(Some(signer), call)),
+		2 => (account(), points_call()).prop_map(|(signer, call)| Op::Dispatch(Some(signer), call)),
+		1 => (account(), payment_call())
+			.prop_map(|(signer, call)| Op::Dispatch(Some(signer), call)),
+		1 => moment().prop_map(Op::Wait),
+	]
+}
+
+/// The total balance of all accounts the calls can move money between.
+///
+/// The treasuries of the clubs may share an account, as `u64` account ids truncate the
+/// sub-account seed, so every account is only counted once.
+fn total_balance() -> BalanceOf<Test> {
+	let treasuries = CLUBS.iter().map(|club_id| TemplateModule::treasury_account(*club_id));
+	ACCOUNTS
+		.iter()
+		.copied()
+		.chain(treasuries)
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.map(|who| <Test as crate::Config>::Currency::total_balance(&who))
+		.fold(Default::default(), |total, balance| total + balance)
+}
+
+/// The membership expiries of all clubs.
+fn expiries() -> BTreeMap<(ClubId, u64), u64> {
+	PalletStorage::<Test>::iter()
+		.flat_map(|(club_id, club)| {
+			club.members
+				.into_iter()
+				.map(move |(member, expiry)| ((club_id, member), expiry))
+		})
+		.collect()
+}
+
+/// Everything about a club that only its owner may change.
+fn club_settings(club_id: ClubId) -> Vec<u8> {
+	(
+		PalletStorage::<Test>::get(club_id).map(|club| (club.owner, club.annual_expenses)),
+		AdmissionPolicies::<Test>::get(club_id),
+		GroupTiers::<Test>::get(club_id),
+		TrialLengths::<Test>::get(club_id),
+		LifetimePrices::<Test>::get(club_id),
+		JoinModes::<Test>::get(club_id),
+		FeePolicies::<Test>::get(club_id),
+	)
+		.encode()
+}
+
+fn run(ops: Vec<Op>) -> Result<(), TestCaseError> {
+	new_test_ext().execute_with(|| {
+		let balance = total_balance();
+		for op in ops {
+			let (signer, call) = match op {
+				Op::Wait(time) => {
+					Timestamp::set_timestamp(Timestamp::get().saturating_add(time));
+					continue
+				},
+				Op::Dispatch(signer, call) => (signer, call),
+			};
+			let owners: Vec<_> = CLUBS
+				.iter()
+				.map(|club_id| PalletStorage::<Test>::get(club_id).map(|club| club.owner))
+				.collect();
+			let settings: Vec<_> = CLUBS.iter().map(|club_id| club_settings(*club_id)).collect();
+			let before = expiries();
+
+			let origin = signer.map_or_else(RuntimeOrigin::root, RuntimeOrigin::signed);
+			let _ = RuntimeCall::TemplateModule(call.clone()).dispatch(origin);
+
+			prop_assert_eq!(total_balance(), balance, "balances not conserved by {:?}", call);
+			for (key, expiry) in expiries() {
+				if let Some(previous) = before.get(&key) {
+					prop_assert!(
+						expiry >= *previous,
+						"expiry of {:?} decreased by {:?}",
+						key,
+						call
+					);
+				}
+			}
+			if let Some(signer) = signer {
+				for (index, club_id) in CLUBS.iter().enumerate() {
+					prop_assert!(
+						club_settings(*club_id) == settings[index] || owners[index] == Some(signer),
+						"club {} changed by {} calling {:?}",
+						club_id,
+						signer,
+						call
+					);
+				}
+			}
+			prop_assert_eq!(TemplateModule::do_try_state(), Ok(()), "after {:?}", call);
+		}
+		Ok(())
+	})
+}
+
+proptest! {
+	#[test]
+	fn random_calls_keep_properties(ops in prop::collection::vec(op(), 0..64)) {
+		run(ops)?;
+	}
+}
@@ -0,0 +1,178 @@
+//! Transaction pool validation of club calls.
+//!
+//! [`CheckClubCall`] rejects club extrinsics that are bound to fail before they enter the pool,
+//! so their senders do not pay fees for them, and gives membership renewals a higher priority.
+
+use crate::{ClubEvents, ClubId, Config, EventId, PalletStorage, Suspended, WeightInfo};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	dispatch::DispatchInfo,
+	sp_runtime::{
+		traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+		transaction_validity::{
+			InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+		},
+	},
+	traits::{Get, IsSubType},
+};
+use scale_info::TypeInfo;
+
+/// Custom validity errors returned by [`CheckClubCall`].
+#[repr(u8)]
+pub enum ValidityError {
+	/// The club does not exist.
+	ClubDoesNotExist = 0,
+	/// The event does not exist.
+	EventDoesNotExist = 1,
+	/// The signer is not a member of the club.
+	NotMember = 2,
+	/// The signer is a suspended member of the club.
+	MemberSuspended = 3,
+	/// The signer does not own the club.
+	NotOwner = 4,
+}
+
+impl From<ValidityError> for u8 {
+	fn from(err: ValidityError) -> Self {
+		err as u8
+	}
+}
+
+impl From<ValidityError> for TransactionValidityError {
+	fn from(err: ValidityError) -> Self {
+		InvalidTransaction::Custom(err.into()).into()
+	}
+}
+
+/// Validate club calls at pool admission.
+///
+/// Membership renewals of an unknown club or by a non-member, and admin calls by anyone other
+/// than the owner of the club, are rejected. Valid renewals get `Config::RenewalPriority`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckClubCall<T: Config>(PhantomData<T>);
+
+impl<T: Config> Default for CheckClubCall<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config> CheckClubCall<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Check `call` signed by `who` against the current state of the pallet.
+	pub fn validate_club_call(who: &T::AccountId, call: &crate::Call<T>) -> TransactionValidity {
+		use crate::Call;
+
+		match call {
+			Call::pay_membership_expense { club_id, .. } |
+			Call::pay_membership_with_points { club_id, .. } => {
+				let club =
+					PalletStorage::<T>::get(club_id).ok_or(ValidityError::ClubDoesNotExist)?;
+				if !club.members.contains_key(who) {
+					return Err(ValidityError::NotMember.into())
+				}
+				if Suspended::<T>::contains_key(club_id, who) {
+					return Err(ValidityError::MemberSuspended.into())
+				}
+				Ok(ValidTransaction { priority: T::RenewalPriority::get(), ..Default::default() })
+			},
+			Call::add_member { club_id, .. } |
+			Call::transfer_ownership { club_id, .. } |
+			Call::set_annual_expense { club_id, .. } |
+			Call::suspend_member { club_id, .. } |
+			Call::unsuspend_member { club_id, .. } |
+			Call::ban_account { club_id, .. } |
+			Call::unban_account { club_id, .. } |
+			Call::set_admission_policy { club_id, .. } |
+			Call::set_group_tier { club_id, .. } |
+			Call::set_trial_length { club_id, .. } |
+			Call::set_lifetime_price { club_id, .. } |
+			Call::create_event { club_id, .. } |
+			Call::grant_points { club_id, .. } |
+			Call::deduct_points { club_id, .. } |
+			Call::set_join_mode { club_id, .. } |
+			Call::set_fee_policy { club_id, .. } => Self::check_owner(who, *club_id),
+			Call::check_in { event_id, .. } | Call::cancel_event { event_id } =>
+				Self::check_event_owner(who, *event_id),
+			_ => Ok(ValidTransaction::default()),
+		}
+	}
+
+	fn check_owner(who: &T::AccountId, club_id: ClubId) -> TransactionValidity {
+		let club = PalletStorage::<T>::get(club_id).ok_or(ValidityError::ClubDoesNotExist)?;
+		if &club.owner != who {
+			return Err(ValidityError::NotOwner.into())
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn check_event_owner(who: &T::AccountId, event_id: EventId) -> TransactionValidity {
+		let event = ClubEvents::<T>::get(event_id).ok_or(ValidityError::EventDoesNotExist)?;
+		Self::check_owner(who, event.club_id)
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for CheckClubCall<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckClubCall")
+	}
+}
+
+impl<T> SignedExtension for CheckClubCall<T>
+where
+	T: Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo> + IsSubType<crate::Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckClubCall";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(call) => Self::validate_club_call(who, call),
+			None => Ok(ValidTransaction::default()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len)?;
+		if call.is_sub_type().is_some() {
+			// The checks are not part of the weight of the calls, so account for them here,
+			// within the limit of the dispatch class. The club is decoded with all its members.
+			let weight = <T as Config>::WeightInfo::check_club_call(T::MaxMembers::get());
+			let limits = T::BlockWeights::get();
+			let max_total = limits.get(info.class).max_total.unwrap_or(limits.max_block);
+			let consumed =
+				frame_system::Pallet::<T>::block_weight().get(info.class).saturating_add(weight);
+			if !consumed.all_lte(max_total) {
+				return Err(InvalidTransaction::ExhaustsResources.into())
+			}
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, info.class);
+		}
+		Ok(())
+	}
+}
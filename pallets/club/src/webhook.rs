@@ -0,0 +1,262 @@
+//! Offchain expiry notifications.
+//!
+//! When the node configures a webhook under [`WEBHOOK_KEY`] in persistent offchain storage, the
+//! offchain worker POSTs a JSON notification to it for each membership that expires within the
+//! configured window, taking the upcoming expiries from the index of [`crate::offchain`]:
+//!
+//! ```json
+//! {"clubId":5,"member":"0x3900000000000000","expiresAt":31622400000}
+//! ```
+//!
+//! `member` is the hex of the SCALE encoded account. Each reminder is sent once per expiry, so a
+//! renewal that moves the expiry leads to a new reminder later. Failed deliveries are retried
+//! with exponential backoff, up to [`MAX_ATTEMPTS`] times. The delivery state of an expiry is
+//! cleared once the expiry leaves the index, because it passed or a renewal moved it.
+//!
+//! Only URLs accepted by [`validate_webhook_url`] are contacted.
+
+use crate::{ClubId, Config, Pallet, UpcomingExpiry, EXPIRIES_KEY};
+use codec::{Decode, Encode};
+use core::fmt::Write;
+use frame_support::{
+	sp_io,
+	sp_runtime::{
+		offchain::{
+			http,
+			storage::StorageValueRef,
+			storage_lock::{StorageLock, Time},
+			Duration,
+		},
+		SaturatedConversion,
+	},
+	RuntimeDebug,
+};
+use pallet_timestamp as timestamp;
+use sp_std::{vec, vec::Vec};
+
+/// The key of the [`WebhookConfig`] in persistent offchain storage.
+pub const WEBHOOK_KEY: &[u8] = b"club::webhook::config";
+const DELIVERY_PREFIX: &[u8] = b"club::webhook::delivery::";
+/// The key of the expiries that have a delivery state, so that it can be cleared.
+const TRACKED_KEY: &[u8] = b"club::webhook::tracked";
+const LOCK_KEY: &[u8] = b"club::webhook::lock";
+
+/// The number of attempts to deliver a notification before giving up.
+pub const MAX_ATTEMPTS: u32 = 8;
+/// The delay before the first retry, doubled after each further failure.
+pub const BASE_BACKOFF_MS: u64 = 6_000;
+/// How long to wait for the endpoint to answer.
+const REQUEST_TIMEOUT_MS: u64 = 2_000;
+/// The most notifications sent by a single run of the offchain worker.
+const MAX_PER_RUN: usize = 16;
+/// The longest webhook URL accepted.
+pub const MAX_URL_LEN: usize = 2_048;
+
+/// Where and when to send expiry notifications, set by the node operator.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct WebhookConfig {
+	/// The URL of the endpoint.
+	pub url: Vec<u8>,
+	/// How long before expiry, in milliseconds, a member is notified.
+	pub window: u64,
+}
+
+/// The delivery state of a single notification.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum Delivery {
+	/// Not delivered after `attempts` attempts, next try not before `retry_at`.
+	Retry {
+		attempts: u32,
+		retry_at: u64,
+	},
+	Delivered,
+	GaveUp,
+}
+
+impl Delivery {
+	/// The state after attempt number `attempts` failed at offchain time `now`.
+	pub fn after_failure(attempts: u32, now: u64) -> Self {
+		if attempts >= MAX_ATTEMPTS {
+			return Self::GaveUp
+		}
+		let backoff = BASE_BACKOFF_MS.saturating_mul(1u64 << (attempts - 1).min(32));
+		Self::Retry { attempts, retry_at: now.saturating_add(backoff) }
+	}
+}
+
+/// Why a webhook URL was rejected.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum WebhookUrlError {
+	/// Longer than [`MAX_URL_LEN`].
+	TooLong,
+	/// Not valid UTF-8.
+	NotUtf8,
+	/// Contains whitespace or control characters.
+	InvalidCharacter,
+	/// Neither `http://` nor `https://`.
+	UnsupportedScheme,
+	/// Carries a user name or password, which would be sent in the clear with every request.
+	Credentials,
+	/// No host after the scheme.
+	MissingHost,
+	/// A port that is not a number between 1 and 65535.
+	InvalidPort,
+}
+
+/// Check that `url` is an absolute `http` or `https` URL with a host, returning it as a string.
+pub fn validate_webhook_url(url: &[u8]) -> Result<&str, WebhookUrlError> {
+	if url.len() > MAX_URL_LEN {
+		return Err(WebhookUrlError::TooLong)
+	}
+	let url = sp_std::str::from_utf8(url).map_err(|_| WebhookUrlError::NotUtf8)?;
+	if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+		return Err(WebhookUrlError::InvalidCharacter)
+	}
+	let rest = url
+		.strip_prefix("http://")
+		.or_else(|| url.strip_prefix("https://"))
+		.ok_or(WebhookUrlError::UnsupportedScheme)?;
+	let authority = rest.split(|c: char| matches!(c, '/' | '?' | '#')).next().unwrap_or_default();
+	if authority.contains('@') {
+		return Err(WebhookUrlError::Credentials)
+	}
+
+	// An IPv6 host is enclosed in brackets, so only a colon after them starts the port.
+	let (host, port) = match authority.strip_prefix('[') {
+		Some(v6) => {
+			let (host, rest) = v6.split_once(']').ok_or(WebhookUrlError::MissingHost)?;
+			match rest {
+				"" => (host, None),
+				rest => (host, Some(rest.strip_prefix(':').ok_or(WebhookUrlError::InvalidPort)?)),
+			}
+		},
+		None => match authority.split_once(':') {
+			Some((host, port)) => (host, Some(port)),
+			None => (authority, None),
+		},
+	};
+	if host.is_empty() {
+		return Err(WebhookUrlError::MissingHost)
+	}
+	if let Some(port) = port {
+		if !port.bytes().all(|b| b.is_ascii_digit()) || !matches!(port.parse::<u16>(), Ok(1..)) {
+			return Err(WebhookUrlError::InvalidPort)
+		}
+	}
+	Ok(url)
+}
+
+/// The key of the [`Delivery`] state of the notification of `entry`.
+pub fn delivery_key<AccountId: Encode, Moment: Encode>(
+	entry: &UpcomingExpiry<AccountId, Moment>,
+) -> Vec<u8> {
+	[DELIVERY_PREFIX, &(entry.club_id, &entry.member, &entry.expires_at).encode()].concat()
+}
+
+struct Json(Vec<u8>);
+
+impl Write for Json {
+	fn write_str(&mut self, s: &str) -> core::fmt::Result {
+		self.0.extend_from_slice(s.as_bytes());
+		Ok(())
+	}
+}
+
+/// The JSON body notifying that `member` of `club_id` expires at `expires_at`.
+pub fn notification_body<AccountId: Encode>(
+	club_id: ClubId,
+	member: &AccountId,
+	expires_at: u64,
+) -> Vec<u8> {
+	let mut json = Json(Vec::new());
+	let _ = write!(json, "{{\"clubId\":{},\"member\":\"0x", club_id);
+	for byte in member.encode() {
+		let _ = write!(json, "{:02x}", byte);
+	}
+	let _ = write!(json, "\",\"expiresAt\":{}}}", expires_at);
+	json.0
+}
+
+impl<T: Config> Pallet<T> {
+	/// Notify the configured webhook of the memberships expiring soon.
+	pub(crate) fn send_expiry_notifications() {
+		let Ok(Some(config)) = StorageValueRef::persistent(WEBHOOK_KEY).get::<WebhookConfig>()
+		else {
+			return
+		};
+		let Ok(url) = validate_webhook_url(&config.url) else { return };
+
+		// Another worker is sending already, leave the notifications to it.
+		let mut lock = StorageLock::<Time>::new(LOCK_KEY);
+		let Ok(_guard) = lock.try_lock() else { return };
+
+		let until = <timestamp::Pallet<T>>::get()
+			.saturated_into::<u64>()
+			.saturating_add(config.window);
+		let upcoming: Vec<UpcomingExpiry<T::AccountId, T::Moment>> =
+			StorageValueRef::persistent(EXPIRIES_KEY)
+				.get()
+				.ok()
+				.flatten()
+				.unwrap_or_default();
+
+		let tracked = StorageValueRef::persistent(TRACKED_KEY);
+		let mut entries: Vec<UpcomingExpiry<T::AccountId, T::Moment>> =
+			tracked.get().ok().flatten().unwrap_or_default();
+		entries.retain(|entry| {
+			let start = upcoming.partition_point(|other| other.expires_at < entry.expires_at);
+			let indexed = upcoming[start..]
+				.iter()
+				.take_while(|other| other.expires_at == entry.expires_at)
+				.any(|other| other == entry);
+			if !indexed {
+				StorageValueRef::persistent(&delivery_key(entry)).clear();
+			}
+			indexed
+		});
+
+		let mut sent = 0;
+		for entry in upcoming {
+			let expires_at = entry.expires_at.saturated_into::<u64>();
+			if expires_at > until || sent == MAX_PER_RUN {
+				break
+			}
+
+			let key = delivery_key(&entry);
+			let state = StorageValueRef::persistent(&key);
+			let now = sp_io::offchain::timestamp().unix_millis();
+			let attempts = match state.get::<Delivery>() {
+				Ok(Some(Delivery::Retry { attempts, retry_at })) if retry_at <= now => attempts,
+				Ok(None) => 0,
+				_ => continue,
+			};
+
+			sent += 1;
+			let body = notification_body(entry.club_id, &entry.member, expires_at);
+			let next = match Self::post_notification(url, body) {
+				Ok(()) => Delivery::Delivered,
+				Err(_) => Delivery::after_failure(attempts + 1, now),
+			};
+			state.set(&next);
+			if attempts == 0 {
+				entries.push(entry);
+			}
+		}
+		tracked.set(&entries);
+	}
+
+	fn post_notification(url: &str, body: Vec<u8>) -> Result<(), http::Error> {
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(REQUEST_TIMEOUT_MS));
+		let pending = http::Request::post(url, vec![body])
+			.add_header("Content-Type", "application/json")
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?;
+		let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+		if (200..300).contains(&response.code) {
+			Ok(())
+		} else {
+			Err(http::Error::Unknown)
+		}
+	}
+}
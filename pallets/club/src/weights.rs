@@ -1,13 +1,12 @@
 
-//! Autogenerated weights for pallet_club
+//! Weights for pallet_club
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! THESE WEIGHTS ARE ESTIMATES, NOT BENCHMARK RESULTS. The components and the storage reads and
+//! writes follow the benchmarks in `benchmarking.rs`, but the execution times and proof sizes
+//! have not been measured. Regenerate this file with the command below on reference hardware
+//! before using the pallet in production.
 
-// Executed Command:
+// Command:
 // ../../target/release/node-template
 // benchmark
 // pallet
@@ -22,7 +21,7 @@
 // --execution=wasm
 // --wasm-execution=compiled
 // --output
-// pallets/template/src/weights.rs
+// pallets/club/src/weights.rs
 // --template
 // ../../.maintain/frame-weight-template.hbs
 
@@ -35,116 +34,783 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_club.
 pub trait WeightInfo {
-	fn create_club() -> Weight;
-	fn add_member() -> Weight;
-	fn transfer_ownership() -> Weight;
-	fn set_annual_expense() -> Weight;
-	fn pay_membership_expense() -> Weight;
+	fn create_club(m: u32, ) -> Weight;
+	fn add_member(m: u32, ) -> Weight;
+	fn transfer_ownership(m: u32, ) -> Weight;
+	fn set_annual_expense(m: u32, ) -> Weight;
+	fn pay_membership_expense(m: u32, d: u32, ) -> Weight;
+	fn suspend_member(m: u32, d: u32, ) -> Weight;
+	fn unsuspend_member(m: u32, d: u32, ) -> Weight;
+	fn ban_account(m: u32, d: u32, ) -> Weight;
+	fn unban_account(m: u32, ) -> Weight;
+	fn set_admission_policy(m: u32, ) -> Weight;
+	fn gift_membership(m: u32, d: u32, ) -> Weight;
+	fn set_group_tier(m: u32, ) -> Weight;
+	fn pay_group_membership(m: u32, d: u32, ) -> Weight;
+	fn add_dependent(m: u32, d: u32, ) -> Weight;
+	fn remove_dependent(m: u32, d: u32, ) -> Weight;
+	fn set_trial_length(m: u32, ) -> Weight;
+	fn start_trial(m: u32, ) -> Weight;
+	fn set_lifetime_price(m: u32, ) -> Weight;
+	fn pay_lifetime_membership(m: u32, d: u32, ) -> Weight;
+	fn create_event(m: u32, ) -> Weight;
+	fn buy_ticket(m: u32, ) -> Weight;
+	fn check_in(m: u32, ) -> Weight;
+	fn settle_event(m: u32, ) -> Weight;
+	fn cancel_event(t: u32, m: u32, ) -> Weight;
+	fn pay_membership_with_points(m: u32, d: u32, ) -> Weight;
+	fn grant_points(m: u32, ) -> Weight;
+	fn deduct_points(m: u32, ) -> Weight;
+	fn set_join_mode(m: u32, ) -> Weight;
+	fn join_with_stake(m: u32, ) -> Weight;
+	fn unstake() -> Weight;
+	fn withdraw_stake(m: u32, d: u32, ) -> Weight;
+	fn set_fee_policy(m: u32, ) -> Weight;
+	fn check_club_call(m: u32, ) -> Weight;
+	fn claim_refund() -> Weight;
+	fn set_payout_destination() -> Weight;
+	fn active_members(m: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_club using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn create_club() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 1000]`.
+	fn create_club(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn add_member() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 999]`.
+	fn add_member(m: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 1523)
+			.saturating_add(Weight::from_parts(52_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn transfer_ownership(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_annual_expense(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn set_annual_expense() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_membership_expense(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 1523)
+			.saturating_add(Weight::from_parts(49_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn suspend_member(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn unsuspend_member(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn ban_account(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn unban_account(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_admission_policy(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn gift_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_group_tier(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_group_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 15]`.
+	fn add_dependent(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[1, 16]`.
+	fn remove_dependent(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_trial_length(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 999]`.
+	fn start_trial(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_lifetime_price(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_lifetime_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
 	}
 
-	fn pay_membership_expense() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
+	/// The range of component `m` is `[0, 1000]`.
+	fn create_event(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	fn buy_ticket(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn check_in(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn settle_event(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `t` is `[0, 1000]`.
+	/// The range of component `m` is `[0, 1000]`.
+	fn cancel_event(t: u32, m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_membership_with_points(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn grant_points(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn deduct_points(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_join_mode(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 999]`.
+	fn join_with_stake(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	fn unstake() -> Weight {
 		Weight::from_parts(6_000_000, 1489)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn withdraw_stake(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_fee_policy(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	fn check_club_call(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	fn claim_refund() -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	fn set_payout_destination() -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	/// The range of component `d` is `[0, 16000]`.
+	fn active_members(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(2_000_000, 1523)
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(1_300_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn create_club() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 7_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 1000]`.
+	fn create_club(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn add_member() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 7_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 999]`.
+	fn add_member(m: u32, ) -> Weight {
+		Weight::from_parts(9_000_000, 1523)
+			.saturating_add(Weight::from_parts(52_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn transfer_ownership(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 7_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_annual_expense(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
 	}
 
-	fn set_annual_expense() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 7_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_membership_expense(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 1523)
+			.saturating_add(Weight::from_parts(49_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn suspend_member(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn unsuspend_member(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn ban_account(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn unban_account(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_admission_policy(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn gift_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_group_tier(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_group_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 15]`.
+	fn add_dependent(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[1, 16]`.
+	fn remove_dependent(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_trial_length(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 999]`.
+	fn start_trial(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_lifetime_price(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_lifetime_membership(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
 	}
 
-	fn pay_membership_expense() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 7_000_000 picoseconds.
+	/// The range of component `m` is `[0, 1000]`.
+	fn create_event(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	fn buy_ticket(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn check_in(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn settle_event(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `t` is `[0, 1000]`.
+	/// The range of component `m` is `[0, 1000]`.
+	fn cancel_event(t: u32, m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn pay_membership_with_points(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn grant_points(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn deduct_points(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_join_mode(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[0, 999]`.
+	fn join_with_stake(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	fn unstake() -> Weight {
 		Weight::from_parts(6_000_000, 1489)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 16]`.
+	fn withdraw_stake(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	fn set_fee_policy(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	/// The range of component `m` is `[1, 1000]`.
+	fn check_club_call(m: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 1523)
+			.saturating_add(Weight::from_parts(50_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(m.into()))
+	}
+
+	fn claim_refund() -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	fn set_payout_destination() -> Weight {
+		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// The range of component `m` is `[0, 1000]`.
+	/// The range of component `d` is `[0, 16000]`.
+	fn active_members(m: u32, d: u32, ) -> Weight {
+		Weight::from_parts(2_000_000, 1523)
+			.saturating_add(Weight::from_parts(900_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(1_300_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2545).saturating_mul(d.into()))
+	}
 }
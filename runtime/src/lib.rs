@@ -16,7 +16,7 @@ use sp_runtime::{
 	traits::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -68,6 +68,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -104,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -224,7 +227,7 @@ impl pallet_grandpa::Config for Runtime {
 
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
 	type WeightInfo = ();
@@ -274,6 +277,20 @@ impl pallet_club::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_club::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
+	type PalletId = ClubPalletId;
+	type MaxEventCapacity = ConstU32<1_000>;
+	type MaxMembers = ConstU32<1_000>;
+	type MaxDependents = ConstU32<16>;
+	type PointsPerRenewal = ConstU32<10>;
+	type PointsPerTenureYear = ConstU32<5>;
+	type PointsPerAttendance = ConstU32<3>;
+	type PointsPerReferral = ConstU32<20>;
+	type PointsPerDiscountUnit = ConstU32<100>;
+	type MaxLedgerEntries = ConstU32<64>;
+	type RenewalPriority = ConstU64<{ TransactionPriority::max_value() / 4 }>;
+	type MembershipChanged = ();
+	// 366 days of milliseconds, the unit of `Moment`.
+	type MomentsPerYear = ConstU64<31_622_400_000>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -311,6 +328,8 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_club::CheckClubCall<Runtime>,
+	pallet_club::SponsorClubFees<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
@@ -326,8 +345,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// The storage migrations to run on runtime upgrades.
+pub type Migrations = (pallet_club::migrations::v1::MigrateToV1<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -488,6 +511,62 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
+	impl pallet_club_runtime_api::ClubApi<Block, AccountId, Moment> for Runtime {
+		fn club_ids() -> Vec<pallet_club::ClubId> {
+			TemplateModule::club_ids()
+		}
+
+		fn club(club_id: pallet_club::ClubId) -> Option<pallet_club::ClubInfo<AccountId>> {
+			TemplateModule::club_info(club_id)
+		}
+
+		fn is_active_member(club_id: pallet_club::ClubId, who: AccountId) -> bool {
+			TemplateModule::is_active_member(club_id, &who)
+		}
+
+		fn membership_expiry(club_id: pallet_club::ClubId, who: AccountId) -> Option<Moment> {
+			TemplateModule::membership_expiry(club_id, &who)
+		}
+
+		fn members(
+			club_id: pallet_club::ClubId,
+			cursor: Option<AccountId>,
+			limit: u32,
+		) -> Vec<pallet_club::MemberInfo<AccountId, Moment>> {
+			TemplateModule::members_page(club_id, cursor, limit)
+		}
+
+		fn clubs_of(who: AccountId) -> Vec<pallet_club::ClubId> {
+			TemplateModule::clubs_of(&who)
+		}
+
+		fn stakes(club_id: pallet_club::ClubId) -> Vec<(AccountId, u32)> {
+			TemplateModule::club_stakes(club_id)
+		}
+
+		fn dependents(club_id: pallet_club::ClubId) -> Vec<(AccountId, AccountId)> {
+			TemplateModule::club_dependents(club_id)
+		}
+
+		fn quote_renewal(
+			club_id: pallet_club::ClubId,
+			who: AccountId,
+			expense: u32,
+		) -> Option<pallet_club::RenewalQuote<Moment>> {
+			TemplateModule::quote_renewal(club_id, &who, expense)
+		}
+
+		fn events() -> Vec<pallet_club::ClubEventInfo<AccountId>> {
+			System::read_events_no_consensus()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::TemplateModule(event) => TemplateModule::event_info(event),
+					_ => None,
+				})
+				.collect()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{